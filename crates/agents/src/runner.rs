@@ -842,7 +842,7 @@ pub async fn run_agent_loop_with_context(
                 Ok(HookAction::ModifyPayload(_)) => {
                     debug!("BeforeLLMCall ModifyPayload ignored (messages are typed)");
                 },
-                Ok(HookAction::Continue) => {},
+                Ok(HookAction::Continue | HookAction::Retry { .. }) => {},
                 Err(e) => {
                     warn!(error = %e, "BeforeLLMCall hook dispatch failed");
                 },
@@ -984,7 +984,7 @@ pub async fn run_agent_loop_with_context(
                 Ok(HookAction::ModifyPayload(_)) => {
                     debug!("AfterLLMCall ModifyPayload ignored (response is typed)");
                 },
-                Ok(HookAction::Continue) => {},
+                Ok(HookAction::Continue | HookAction::Retry { .. }) => {},
                 Err(e) => {
                     warn!(error = %e, "AfterLLMCall hook dispatch failed");
                 },
@@ -1082,7 +1082,7 @@ pub async fn run_agent_loop_with_context(
                             Ok(HookAction::ModifyPayload(v)) => {
                                 args = v;
                             },
-                            Ok(HookAction::Continue) => {},
+                            Ok(HookAction::Continue | HookAction::Retry { .. }) => {},
                             Err(e) => {
                                 warn!(tool = %tc_name, error = %e, "BeforeToolCall hook dispatch failed");
                             },
@@ -1326,7 +1326,7 @@ pub async fn run_agent_loop_streaming(
                 Ok(HookAction::ModifyPayload(_)) => {
                     debug!("BeforeLLMCall ModifyPayload ignored (messages are typed)");
                 },
-                Ok(HookAction::Continue) => {},
+                Ok(HookAction::Continue | HookAction::Retry { .. }) => {},
                 Err(e) => {
                     warn!(error = %e, "BeforeLLMCall hook dispatch failed");
                 },
@@ -1586,7 +1586,7 @@ pub async fn run_agent_loop_streaming(
                 Ok(HookAction::ModifyPayload(_)) => {
                     debug!("AfterLLMCall ModifyPayload ignored (response is typed)");
                 },
-                Ok(HookAction::Continue) => {},
+                Ok(HookAction::Continue | HookAction::Retry { .. }) => {},
                 Err(e) => {
                     warn!(error = %e, "AfterLLMCall hook dispatch failed");
                 },
@@ -1691,7 +1691,7 @@ pub async fn run_agent_loop_streaming(
                             Ok(HookAction::ModifyPayload(v)) => {
                                 args = v;
                             }
-                            Ok(HookAction::Continue) => {}
+                            Ok(HookAction::Continue | HookAction::Retry { .. }) => {}
                             Err(e) => {
                                 warn!(tool = %tc_name, error = %e, "BeforeToolCall hook dispatch failed");
                             }
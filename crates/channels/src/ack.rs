@@ -0,0 +1,247 @@
+use {
+    anyhow::Result,
+    async_trait::async_trait,
+    serde::{Deserialize, Serialize},
+};
+
+/// How a channel acknowledges that a turn is in progress.
+///
+/// Every plugin used to reimplement progress feedback (typing loops,
+/// placeholder messages, reactions). The inbound path invokes
+/// [`begin_ack`]/[`end_ack`] around each turn and the plugin maps the
+/// strategy to its native mechanism via [`AckAdapter`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AckStrategy {
+    /// No progress feedback.
+    #[default]
+    None,
+    /// Show a typing indicator for the duration of the turn.
+    Typing,
+    /// Post a placeholder message, removed (or edited over) when done.
+    Placeholder,
+    /// React to the inbound message while the turn runs.
+    Reaction,
+}
+
+/// Per-channel mapping of ack strategies to native mechanisms.
+///
+/// Defaults are no-ops so a plugin only implements what its platform
+/// supports; unsupported strategies silently degrade to [`AckStrategy::None`].
+#[async_trait]
+pub trait AckAdapter: Send + Sync {
+    /// Start a typing indicator in the chat.
+    async fn start_typing(&self, _account_id: &str, _chat_id: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Post a placeholder message; returns its platform message ID.
+    async fn send_placeholder(&self, _account_id: &str, _chat_id: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Remove a previously posted placeholder.
+    async fn clear_placeholder(
+        &self,
+        _account_id: &str,
+        _chat_id: &str,
+        _message_id: &str,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// React to the inbound message (e.g. 👀 / hourglass).
+    async fn add_reaction(&self, _account_id: &str, _chat_id: &str, _message_id: &str)
+    -> Result<()> {
+        Ok(())
+    }
+
+    /// Remove the progress reaction.
+    async fn remove_reaction(
+        &self,
+        _account_id: &str,
+        _chat_id: &str,
+        _message_id: &str,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// State carried from turn start to turn end.
+#[derive(Debug, Default)]
+pub struct AckToken {
+    strategy: AckStrategy,
+    /// Placeholder message ID, when one was posted.
+    placeholder_id: Option<String>,
+    /// Inbound message ID a reaction was added to.
+    reacted_to: Option<String>,
+}
+
+/// Invoke the channel's progress feedback at turn start.
+///
+/// `inbound_message_id` is the platform ID of the message being answered
+/// (required for [`AckStrategy::Reaction`]). Failures are returned so the
+/// caller can log them, but the turn should proceed regardless.
+pub async fn begin_ack(
+    strategy: AckStrategy,
+    adapter: &dyn AckAdapter,
+    account_id: &str,
+    chat_id: &str,
+    inbound_message_id: Option<&str>,
+) -> Result<AckToken> {
+    let mut token = AckToken {
+        strategy,
+        ..AckToken::default()
+    };
+    match strategy {
+        AckStrategy::None => {},
+        AckStrategy::Typing => adapter.start_typing(account_id, chat_id).await?,
+        AckStrategy::Placeholder => {
+            token.placeholder_id = adapter.send_placeholder(account_id, chat_id).await?;
+        },
+        AckStrategy::Reaction => {
+            if let Some(message_id) = inbound_message_id {
+                adapter.add_reaction(account_id, chat_id, message_id).await?;
+                token.reacted_to = Some(message_id.to_string());
+            }
+        },
+    }
+    Ok(token)
+}
+
+/// Tear down the progress feedback at turn end.
+pub async fn end_ack(
+    token: AckToken,
+    adapter: &dyn AckAdapter,
+    account_id: &str,
+    chat_id: &str,
+) -> Result<()> {
+    match token.strategy {
+        AckStrategy::None | AckStrategy::Typing => {},
+        AckStrategy::Placeholder => {
+            if let Some(id) = token.placeholder_id {
+                adapter.clear_placeholder(account_id, chat_id, &id).await?;
+            }
+        },
+        AckStrategy::Reaction => {
+            if let Some(id) = token.reacted_to {
+                adapter.remove_reaction(account_id, chat_id, &id).await?;
+            }
+        },
+    }
+    Ok(())
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::sync::Mutex,
+    };
+
+    #[derive(Default)]
+    struct RecordingAdapter {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl RecordingAdapter {
+        fn record(&self, call: &str) {
+            self.calls
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(call.to_string());
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap_or_else(|e| e.into_inner()).clone()
+        }
+    }
+
+    #[async_trait]
+    impl AckAdapter for RecordingAdapter {
+        async fn start_typing(&self, _account_id: &str, _chat_id: &str) -> Result<()> {
+            self.record("start_typing");
+            Ok(())
+        }
+
+        async fn send_placeholder(
+            &self,
+            _account_id: &str,
+            _chat_id: &str,
+        ) -> Result<Option<String>> {
+            self.record("send_placeholder");
+            Ok(Some("ph-1".into()))
+        }
+
+        async fn clear_placeholder(
+            &self,
+            _account_id: &str,
+            _chat_id: &str,
+            message_id: &str,
+        ) -> Result<()> {
+            self.record(&format!("clear_placeholder:{message_id}"));
+            Ok(())
+        }
+
+        async fn add_reaction(
+            &self,
+            _account_id: &str,
+            _chat_id: &str,
+            message_id: &str,
+        ) -> Result<()> {
+            self.record(&format!("add_reaction:{message_id}"));
+            Ok(())
+        }
+
+        async fn remove_reaction(
+            &self,
+            _account_id: &str,
+            _chat_id: &str,
+            message_id: &str,
+        ) -> Result<()> {
+            self.record(&format!("remove_reaction:{message_id}"));
+            Ok(())
+        }
+    }
+
+    async fn run(strategy: AckStrategy, inbound_id: Option<&str>) -> Vec<String> {
+        let adapter = RecordingAdapter::default();
+        let token = begin_ack(strategy, &adapter, "acct", "chat", inbound_id)
+            .await
+            .unwrap();
+        end_ack(token, &adapter, "acct", "chat").await.unwrap();
+        adapter.calls()
+    }
+
+    #[tokio::test]
+    async fn none_invokes_nothing() {
+        assert!(run(AckStrategy::None, Some("m1")).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn typing_starts_indicator_only() {
+        assert_eq!(run(AckStrategy::Typing, None).await, ["start_typing"]);
+    }
+
+    #[tokio::test]
+    async fn placeholder_posts_and_clears() {
+        assert_eq!(run(AckStrategy::Placeholder, None).await, [
+            "send_placeholder",
+            "clear_placeholder:ph-1"
+        ]);
+    }
+
+    #[tokio::test]
+    async fn reaction_adds_and_removes_on_inbound_message() {
+        assert_eq!(run(AckStrategy::Reaction, Some("m1")).await, [
+            "add_reaction:m1",
+            "remove_reaction:m1"
+        ]);
+    }
+
+    #[tokio::test]
+    async fn reaction_without_message_id_is_a_no_op() {
+        assert!(run(AckStrategy::Reaction, None).await.is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use {anyhow::Result, async_trait::async_trait};
+
+/// A single recorded access decision.
+///
+/// Unlike the message log (which stores content for forensics), audit
+/// records capture only the gating outcome and its reason, giving
+/// operators a compliance trail that survives message-log pruning.
+#[derive(Debug, Clone)]
+pub struct AuditRecord {
+    pub id: i64,
+    pub account_id: String,
+    pub channel_type: String,
+    pub peer_id: String,
+    pub chat_type: String,
+    /// Whether the inbound message was allowed to proceed.
+    pub allowed: bool,
+    /// Machine-readable reason (e.g. "allowlisted", "dm_policy_disabled").
+    pub reason: String,
+    pub created_at: i64,
+}
+
+/// Append-only store of gating decisions for compliance reporting.
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+    async fn record(&self, record: AuditRecord) -> Result<()>;
+    async fn list_by_account(&self, account_id: &str, limit: u32) -> Result<Vec<AuditRecord>>;
+}
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+
+/// Notice appended when a response is cut.
+const TRUNCATION_NOTICE: &str = "[response too long, truncated]";
+
+/// How an over-budget response is handled.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowStrategy {
+    /// Cut at the budget and append a truncation notice.
+    #[default]
+    Truncate,
+    /// Upload the full text as a file (where the channel supports
+    /// [`crate::ChannelOutbound::send_file`]) and send a short notice.
+    UploadAsFile,
+    /// Split into at most `max_chunks` messages, truncating the last.
+    Split,
+}
+
+/// Opt-in pre-send budget on agent response length, configurable per
+/// channel. Keeps runaway responses from spamming chats; the overflow
+/// strategy decides whether the tail is cut, uploaded, or split.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ResponseBudget {
+    /// Off by default.
+    pub enabled: bool,
+    /// Budget in characters.
+    pub max_chars: usize,
+    /// Message cap for [`OverflowStrategy::Split`].
+    pub max_chunks: usize,
+    pub strategy: OverflowStrategy,
+}
+
+impl Default for ResponseBudget {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_chars: 8_000,
+            max_chunks: 4,
+            strategy: OverflowStrategy::default(),
+        }
+    }
+}
+
+/// What the channel should send for a budget-checked response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BudgetOutcome {
+    /// Within budget (or the budget is disabled) — send as-is.
+    Unchanged,
+    /// Send this truncated text instead.
+    Truncated(String),
+    /// Upload the full text via `send_file` under `filename`, sending
+    /// `notice` as the message/caption.
+    UploadAsFile { filename: String, notice: String },
+    /// Send these messages in order (last one carries the notice).
+    Split(Vec<String>),
+}
+
+impl ResponseBudget {
+    /// Check `text` against the budget. `chunk_size` is the channel's
+    /// per-message character limit, used by the split strategy.
+    #[must_use]
+    pub fn apply(&self, text: &str, chunk_size: usize) -> BudgetOutcome {
+        if !self.enabled || self.max_chars == 0 || text.chars().count() <= self.max_chars {
+            return BudgetOutcome::Unchanged;
+        }
+
+        match self.strategy {
+            OverflowStrategy::Truncate => {
+                // Leave room for the notice so the result stays within budget.
+                let keep = self
+                    .max_chars
+                    .saturating_sub(TRUNCATION_NOTICE.chars().count() + 1);
+                BudgetOutcome::Truncated(truncate_with_notice(text, keep))
+            },
+            OverflowStrategy::UploadAsFile => BudgetOutcome::UploadAsFile {
+                filename: "response.md".to_string(),
+                notice: format!(
+                    "Response is too long for this chat ({} chars) — attached as a file.",
+                    text.chars().count()
+                ),
+            },
+            OverflowStrategy::Split => {
+                let chunk_size = chunk_size.max(1);
+                // Leave room for the notice so the cap on chunk count holds.
+                let budget = self.max_chunks.max(1) * chunk_size;
+                let keep = budget.saturating_sub(TRUNCATION_NOTICE.chars().count() + 1);
+                let capped = truncate_with_notice(text, keep);
+                BudgetOutcome::Split(split_chars(&capped, chunk_size))
+            },
+        }
+    }
+}
+
+/// Cut `text` to at most `max_chars` characters and append the notice.
+fn truncate_with_notice(text: &str, max_chars: usize) -> String {
+    let cut = text
+        .char_indices()
+        .nth(max_chars)
+        .map_or(text.len(), |(i, _)| i);
+    format!("{}\n{TRUNCATION_NOTICE}", text[..cut].trim_end())
+}
+
+/// Split into character-bounded chunks.
+fn split_chars(text: &str, chunk_size: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .chunks(chunk_size)
+        .map(|c| c.iter().collect())
+        .collect()
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(strategy: OverflowStrategy) -> ResponseBudget {
+        ResponseBudget {
+            enabled: true,
+            max_chars: 20,
+            max_chunks: 2,
+            strategy,
+        }
+    }
+
+    #[test]
+    fn disabled_budget_is_unchanged() {
+        let long = "x".repeat(100);
+        assert_eq!(
+            ResponseBudget::default().apply(&long, 10),
+            BudgetOutcome::Unchanged
+        );
+    }
+
+    #[test]
+    fn within_budget_is_unchanged() {
+        let outcome = budget(OverflowStrategy::Truncate).apply("short reply", 10);
+        assert_eq!(outcome, BudgetOutcome::Unchanged);
+    }
+
+    #[test]
+    fn truncate_cuts_and_appends_notice_within_budget() {
+        let long = "a".repeat(100);
+        let budget = ResponseBudget {
+            max_chars: 50,
+            ..budget(OverflowStrategy::Truncate)
+        };
+        match budget.apply(&long, 10) {
+            BudgetOutcome::Truncated(text) => {
+                assert!(text.starts_with("aaa"));
+                assert!(text.ends_with(TRUNCATION_NOTICE));
+                assert!(
+                    text.chars().count() <= 50,
+                    "truncated output must not exceed max_chars"
+                );
+            },
+            other => panic!("expected Truncated, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn upload_strategy_yields_file_and_notice() {
+        let long = "b".repeat(50);
+        match budget(OverflowStrategy::UploadAsFile).apply(&long, 10) {
+            BudgetOutcome::UploadAsFile { filename, notice } => {
+                assert_eq!(filename, "response.md");
+                assert!(notice.contains("50 chars"));
+            },
+            other => panic!("expected UploadAsFile, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn split_caps_chunk_count() {
+        let long = "c".repeat(200);
+        match budget(OverflowStrategy::Split).apply(&long, 40) {
+            BudgetOutcome::Split(chunks) => {
+                assert_eq!(chunks.len(), 2);
+                let joined = chunks.concat();
+                assert!(joined.starts_with("c"));
+                assert!(joined.ends_with(TRUNCATION_NOTICE));
+                assert!(joined.chars().count() <= 80);
+            },
+            other => panic!("expected Split, got {other:?}"),
+        }
+    }
+}
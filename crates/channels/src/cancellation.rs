@@ -0,0 +1,158 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use tokio_util::sync::CancellationToken;
+
+/// Registry of cancellation tokens for in-flight agent turns.
+///
+/// Plugins register a token when starting work on an inbound message so
+/// any channel (or the gateway) can cancel the right turn later — e.g.
+/// when the triggering message is deleted or a stop command arrives.
+/// Entries are keyed by `(account_id, turn_id)` where `turn_id` is a
+/// platform message ID or session key, whichever the channel uses to
+/// identify the turn.
+#[derive(Default)]
+pub struct TurnCancellationRegistry {
+    turns: Mutex<HashMap<(String, String), CancellationToken>>,
+}
+
+impl TurnCancellationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new in-flight turn and return its cancellation token.
+    ///
+    /// Registering the same key twice replaces (and cancels) the previous
+    /// token so a stale entry can't leak.
+    pub fn register(&self, account_id: &str, turn_id: &str) -> CancellationToken {
+        let token = CancellationToken::new();
+        let mut turns = self.turns.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(stale) =
+            turns.insert((account_id.to_string(), turn_id.to_string()), token.clone())
+        {
+            stale.cancel();
+        }
+        token
+    }
+
+    /// Cancel an in-flight turn. Returns `true` if a matching turn was
+    /// registered (its token is resolved and the entry removed).
+    pub fn cancel(&self, account_id: &str, turn_id: &str) -> bool {
+        let removed = {
+            let mut turns = self.turns.lock().unwrap_or_else(|e| e.into_inner());
+            turns.remove(&(account_id.to_string(), turn_id.to_string()))
+        };
+        match removed {
+            Some(token) => {
+                token.cancel();
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Cancel every in-flight turn whose `turn_id` starts with `prefix`,
+    /// returning how many were cancelled.
+    ///
+    /// Channels that scope turn IDs by conversation (e.g.
+    /// `"{chat}:{message}"`) use this to stop all of a chat's turns at
+    /// once without affecting other chats on the account.
+    pub fn cancel_prefixed(&self, account_id: &str, prefix: &str) -> usize {
+        let removed: Vec<CancellationToken> = {
+            let mut turns = self.turns.lock().unwrap_or_else(|e| e.into_inner());
+            let keys: Vec<(String, String)> = turns
+                .keys()
+                .filter(|(aid, tid)| aid == account_id && tid.starts_with(prefix))
+                .cloned()
+                .collect();
+            keys.iter().filter_map(|key| turns.remove(key)).collect()
+        };
+        for token in &removed {
+            token.cancel();
+        }
+        removed.len()
+    }
+
+    /// Mark a turn as finished, dropping its token without cancelling.
+    pub fn complete(&self, account_id: &str, turn_id: &str) {
+        let mut turns = self.turns.lock().unwrap_or_else(|e| e.into_inner());
+        turns.remove(&(account_id.to_string(), turn_id.to_string()));
+    }
+
+    /// Number of in-flight turns for an account.
+    pub fn active_count(&self, account_id: &str) -> usize {
+        let turns = self.turns.lock().unwrap_or_else(|e| e.into_inner());
+        turns.keys().filter(|(aid, _)| aid == account_id).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_and_cancel_resolves_token() {
+        let registry = TurnCancellationRegistry::new();
+        let token = registry.register("bot1", "msg-1");
+        assert!(!token.is_cancelled());
+
+        assert!(registry.cancel("bot1", "msg-1"));
+        assert!(token.is_cancelled());
+        // Entry is gone after cancel.
+        assert!(!registry.cancel("bot1", "msg-1"));
+    }
+
+    #[test]
+    fn complete_removes_without_cancelling() {
+        let registry = TurnCancellationRegistry::new();
+        let token = registry.register("bot1", "msg-1");
+
+        registry.complete("bot1", "msg-1");
+        assert!(!token.is_cancelled());
+        assert_eq!(registry.active_count("bot1"), 0);
+    }
+
+    #[test]
+    fn cancel_unknown_turn_returns_false() {
+        let registry = TurnCancellationRegistry::new();
+        assert!(!registry.cancel("bot1", "nope"));
+    }
+
+    #[test]
+    fn re_register_cancels_stale_token() {
+        let registry = TurnCancellationRegistry::new();
+        let stale = registry.register("bot1", "msg-1");
+        let fresh = registry.register("bot1", "msg-1");
+
+        assert!(stale.is_cancelled());
+        assert!(!fresh.is_cancelled());
+        assert_eq!(registry.active_count("bot1"), 1);
+    }
+
+    #[test]
+    fn cancel_prefixed_stops_only_matching_turns() {
+        let registry = TurnCancellationRegistry::new();
+        let chat_a1 = registry.register("bot1", "chat-a:1");
+        let chat_a2 = registry.register("bot1", "chat-a:2");
+        let chat_b = registry.register("bot1", "chat-b:3");
+        let other_account = registry.register("bot2", "chat-a:4");
+
+        assert_eq!(registry.cancel_prefixed("bot1", "chat-a:"), 2);
+        assert!(chat_a1.is_cancelled());
+        assert!(chat_a2.is_cancelled());
+        assert!(!chat_b.is_cancelled());
+        assert!(!other_account.is_cancelled());
+        assert_eq!(registry.cancel_prefixed("bot1", "chat-a:"), 0);
+    }
+
+    #[test]
+    fn active_count_scopes_by_account() {
+        let registry = TurnCancellationRegistry::new();
+        registry.register("bot1", "a");
+        registry.register("bot1", "b");
+        registry.register("bot2", "c");
+
+        assert_eq!(registry.active_count("bot1"), 2);
+        assert_eq!(registry.active_count("bot2"), 1);
+    }
+}
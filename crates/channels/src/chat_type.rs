@@ -0,0 +1,56 @@
+use moltis_common::types::ChatType;
+
+/// Platform-neutral chat classification, as reported by a channel's
+/// native API before it is collapsed into `ChatType`.
+///
+/// Each plugin maps its raw chat kind (teloxide `ChatKind`, a Discord
+/// guild presence, a Slack channel type, XMPP MUC membership) into this
+/// enum and calls [`infer_chat_type`], so the raw-kind → `ChatType`
+/// decision lives in one place instead of being re-derived ad hoc per
+/// channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawChatKind {
+    /// One-on-one conversation with a single user.
+    Private,
+    /// Small/basic group chat.
+    Group,
+    /// Large or upgraded group (Telegram supergroup, Discord guild
+    /// channel, Slack public/private channel, XMPP MUC).
+    Supergroup,
+    /// Broadcast-style channel where members cannot necessarily post.
+    Broadcast,
+    /// Thread hanging off a group or channel conversation.
+    Thread,
+}
+
+/// Map a raw chat kind to the access-control `ChatType`.
+pub fn infer_chat_type(kind: RawChatKind) -> ChatType {
+    match kind {
+        RawChatKind::Private => ChatType::Dm,
+        RawChatKind::Group | RawChatKind::Supergroup | RawChatKind::Thread => ChatType::Group,
+        RawChatKind::Broadcast => ChatType::Channel,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn private_maps_to_dm() {
+        assert_eq!(infer_chat_type(RawChatKind::Private), ChatType::Dm);
+    }
+
+    #[test]
+    fn group_variants_map_to_group() {
+        assert_eq!(infer_chat_type(RawChatKind::Group), ChatType::Group);
+        assert_eq!(infer_chat_type(RawChatKind::Supergroup), ChatType::Group);
+        // Threads inherit their parent's group semantics for gating.
+        assert_eq!(infer_chat_type(RawChatKind::Thread), ChatType::Group);
+    }
+
+    #[test]
+    fn broadcast_maps_to_channel() {
+        assert_eq!(infer_chat_type(RawChatKind::Broadcast), ChatType::Channel);
+    }
+}
@@ -0,0 +1,188 @@
+use std::{collections::HashMap, sync::Arc};
+
+use {anyhow::Result, async_trait::async_trait};
+
+/// A parsed, channel-agnostic command invocation.
+///
+/// Each channel adapts its native trigger — slash commands, prefix
+/// commands, ad-hoc text — into this shape before dispatch, so a command
+/// like "status" is defined once and behaves identically everywhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandInvocation {
+    /// Command name without any prefix (e.g. "status").
+    pub name: String,
+    /// Whitespace-split arguments after the name.
+    pub args: Vec<String>,
+    /// Channel account the command arrived on.
+    pub account_id: String,
+    /// Sender the reply goes back to.
+    pub peer_id: String,
+}
+
+impl CommandInvocation {
+    /// Parse a prefix-triggered command like `/status verbose` or
+    /// `!status verbose`. Returns `None` when `text` doesn't start with
+    /// `prefix` or has no command name after it.
+    #[must_use]
+    pub fn parse_prefixed(
+        text: &str,
+        prefix: &str,
+        account_id: &str,
+        peer_id: &str,
+    ) -> Option<Self> {
+        let rest = text.trim().strip_prefix(prefix)?;
+        let mut parts = rest.split_whitespace();
+        let name = parts.next()?;
+        Some(Self {
+            name: name.to_lowercase(),
+            args: parts.map(ToString::to_string).collect(),
+            account_id: account_id.to_string(),
+            peer_id: peer_id.to_string(),
+        })
+    }
+}
+
+/// Handler for one registered command.
+#[async_trait]
+pub trait CommandHandler: Send + Sync {
+    /// Run the command and return the reply text for the channel.
+    async fn run(&self, invocation: &CommandInvocation) -> Result<String>;
+}
+
+/// A registered command: name, description for help output, handler.
+pub struct CommandSpec {
+    pub name: String,
+    pub description: String,
+    pub handler: Arc<dyn CommandHandler>,
+}
+
+/// Channel-agnostic command registry.
+///
+/// Commands are registered once; channels map raw payloads into
+/// [`CommandInvocation`]s (via [`CommandInvocation::parse_prefixed`] or
+/// their native structured payloads) and call [`CommandRegistry::dispatch`].
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: HashMap<String, CommandSpec>,
+}
+
+impl CommandRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a command, replacing any previous spec with the same name.
+    pub fn register(&mut self, spec: CommandSpec) {
+        self.commands.insert(spec.name.to_lowercase(), spec);
+    }
+
+    /// Look up a command by name (case-insensitive).
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&CommandSpec> {
+        self.commands.get(&name.to_lowercase())
+    }
+
+    /// Registered command names, sorted for stable help output.
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.commands.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Dispatch an invocation to its handler. Unknown commands error so
+    /// the channel can reply with a fallback message.
+    pub async fn dispatch(&self, invocation: &CommandInvocation) -> Result<String> {
+        let spec = self
+            .get(&invocation.name)
+            .ok_or_else(|| anyhow::anyhow!("unknown command '{}'", invocation.name))?;
+        spec.handler.run(invocation).await
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StatusHandler;
+
+    #[async_trait]
+    impl CommandHandler for StatusHandler {
+        async fn run(&self, invocation: &CommandInvocation) -> Result<String> {
+            Ok(format!(
+                "status for {} (args: {})",
+                invocation.account_id,
+                invocation.args.join(",")
+            ))
+        }
+    }
+
+    fn registry() -> CommandRegistry {
+        let mut registry = CommandRegistry::new();
+        registry.register(CommandSpec {
+            name: "status".into(),
+            description: "Show channel status".into(),
+            handler: Arc::new(StatusHandler),
+        });
+        registry
+    }
+
+    #[test]
+    fn parse_prefixed_extracts_name_and_args() {
+        let inv = CommandInvocation::parse_prefixed("/Status verbose now", "/", "acct", "peer")
+            .unwrap();
+        assert_eq!(inv.name, "status");
+        assert_eq!(inv.args, ["verbose", "now"]);
+
+        assert!(CommandInvocation::parse_prefixed("hello", "/", "acct", "peer").is_none());
+        assert!(CommandInvocation::parse_prefixed("/", "/", "acct", "peer").is_none());
+    }
+
+    #[tokio::test]
+    async fn dispatches_from_two_channel_adapters() {
+        let registry = registry();
+
+        // Adapter 1: a prefix-command channel (e.g. Telegram "/status").
+        let prefixed =
+            CommandInvocation::parse_prefixed("/status verbose", "/", "tg-acct", "user1").unwrap();
+        let reply = registry.dispatch(&prefixed).await.unwrap();
+        assert_eq!(reply, "status for tg-acct (args: verbose)");
+
+        // Adapter 2: a structured slash-command payload (e.g. Slack-style).
+        let payload = serde_json::json!({"command": "status", "text": "verbose"});
+        let structured = CommandInvocation {
+            name: payload["command"].as_str().unwrap().to_string(),
+            args: payload["text"]
+                .as_str()
+                .unwrap()
+                .split_whitespace()
+                .map(ToString::to_string)
+                .collect(),
+            account_id: "slack-acct".into(),
+            peer_id: "user2".into(),
+        };
+        let reply = registry.dispatch(&structured).await.unwrap();
+        assert_eq!(reply, "status for slack-acct (args: verbose)");
+    }
+
+    #[tokio::test]
+    async fn unknown_command_errors() {
+        let registry = registry();
+        let inv = CommandInvocation::parse_prefixed("/nope", "/", "acct", "peer").unwrap();
+        let err = registry.dispatch(&inv).await.unwrap_err();
+        assert!(err.to_string().contains("unknown command"));
+    }
+
+    #[test]
+    fn names_are_sorted() {
+        let mut registry = registry();
+        registry.register(CommandSpec {
+            name: "about".into(),
+            description: "About".into(),
+            handler: Arc::new(StatusHandler),
+        });
+        assert_eq!(registry.names(), ["about", "status"]);
+    }
+}
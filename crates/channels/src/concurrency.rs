@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use {
+    serde::{Deserialize, Serialize},
+    tokio::sync::{OwnedSemaphorePermit, Semaphore},
+};
+
+/// What to do with an inbound turn when an account is already at its
+/// concurrency cap.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Wait for a permit — turns queue up in arrival order.
+    #[default]
+    Queue,
+    /// Silently discard the incoming turn.
+    Drop,
+    /// Refuse the turn so the caller can send a "busy" notice.
+    Reject,
+}
+
+/// Per-account cap on simultaneous in-flight agent turns.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct TurnLimits {
+    /// Maximum concurrent turns per account.
+    pub max_concurrent_turns: usize,
+    /// Behavior when the cap is reached.
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for TurnLimits {
+    fn default() -> Self {
+        Self {
+            max_concurrent_turns: 4,
+            overflow: OverflowPolicy::Queue,
+        }
+    }
+}
+
+/// Outcome of asking the limiter to admit a turn.
+pub enum TurnAdmission {
+    /// The turn may proceed; hold the permit for its duration.
+    Admitted(TurnPermit),
+    /// The turn was discarded under [`OverflowPolicy::Drop`].
+    Dropped,
+    /// The account is at capacity under [`OverflowPolicy::Reject`]; the
+    /// caller should tell the sender the agent is busy.
+    Busy,
+}
+
+/// RAII permit for one in-flight turn; dropping it frees the slot.
+pub struct TurnPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+/// Limits concurrent agent turns per account, shared across channels via
+/// the inbound path.
+///
+/// A flood of messages would otherwise spawn unbounded concurrent turns,
+/// exhausting model rate limits and memory. Each account gets its own
+/// semaphore sized by [`TurnLimits::max_concurrent_turns`].
+pub struct TurnLimiter {
+    limits: TurnLimits,
+    accounts: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl TurnLimiter {
+    #[must_use]
+    pub fn new(limits: TurnLimits) -> Self {
+        Self {
+            limits,
+            accounts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn semaphore(&self, account_id: &str) -> Arc<Semaphore> {
+        let mut accounts = self.accounts.lock().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(
+            accounts
+                .entry(account_id.to_string())
+                .or_insert_with(|| Arc::new(Semaphore::new(self.limits.max_concurrent_turns))),
+        )
+    }
+
+    /// Admit (or queue/drop/reject) a turn for the given account.
+    pub async fn admit(&self, account_id: &str) -> TurnAdmission {
+        let sem = self.semaphore(account_id);
+        match self.limits.overflow {
+            OverflowPolicy::Queue => sem
+                .acquire_owned()
+                .await
+                .map(|permit| TurnAdmission::Admitted(TurnPermit { _permit: permit }))
+                // The semaphore is never closed; treat closure as a drop.
+                .unwrap_or(TurnAdmission::Dropped),
+            OverflowPolicy::Drop => sem
+                .try_acquire_owned()
+                .map(|permit| TurnAdmission::Admitted(TurnPermit { _permit: permit }))
+                .unwrap_or(TurnAdmission::Dropped),
+            OverflowPolicy::Reject => sem
+                .try_acquire_owned()
+                .map(|permit| TurnAdmission::Admitted(TurnPermit { _permit: permit }))
+                .unwrap_or(TurnAdmission::Busy),
+        }
+    }
+
+    /// Free permits remaining for an account (cap when none in flight).
+    #[must_use]
+    pub fn available(&self, account_id: &str) -> usize {
+        self.semaphore(account_id).available_permits()
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use {super::*, std::time::Duration};
+
+    fn limiter(cap: usize, overflow: OverflowPolicy) -> TurnLimiter {
+        TurnLimiter::new(TurnLimits {
+            max_concurrent_turns: cap,
+            overflow,
+        })
+    }
+
+    #[tokio::test]
+    async fn reject_when_at_capacity() {
+        let limiter = limiter(1, OverflowPolicy::Reject);
+        let first = limiter.admit("acct").await;
+        assert!(matches!(first, TurnAdmission::Admitted(_)));
+        assert!(matches!(limiter.admit("acct").await, TurnAdmission::Busy));
+        drop(first);
+        assert!(matches!(
+            limiter.admit("acct").await,
+            TurnAdmission::Admitted(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn drop_policy_discards_excess() {
+        let limiter = limiter(1, OverflowPolicy::Drop);
+        let _held = limiter.admit("acct").await;
+        assert!(matches!(limiter.admit("acct").await, TurnAdmission::Dropped));
+    }
+
+    #[tokio::test]
+    async fn queue_policy_waits_for_free_permit() {
+        let limiter = limiter(1, OverflowPolicy::Queue);
+        let held = limiter.admit("acct").await;
+        // While the permit is held, a queued admit does not resolve.
+        let pending = tokio::time::timeout(Duration::from_millis(20), limiter.admit("acct")).await;
+        assert!(pending.is_err());
+        drop(held);
+        let admitted = tokio::time::timeout(Duration::from_millis(100), limiter.admit("acct"))
+            .await
+            .expect("permit should free after drop");
+        assert!(matches!(admitted, TurnAdmission::Admitted(_)));
+    }
+
+    #[tokio::test]
+    async fn accounts_are_independent() {
+        let limiter = limiter(1, OverflowPolicy::Reject);
+        let _a = limiter.admit("acct-a").await;
+        assert!(matches!(
+            limiter.admit("acct-b").await,
+            TurnAdmission::Admitted(_)
+        ));
+        assert_eq!(limiter.available("acct-a"), 0);
+    }
+}
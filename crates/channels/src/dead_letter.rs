@@ -0,0 +1,69 @@
+use {anyhow::Result, async_trait::async_trait};
+
+use crate::plugin::ChannelOutbound;
+
+/// A failed outbound message parked for operator inspection and retry.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetter {
+    pub id: i64,
+    pub account_id: String,
+    pub channel_type: String,
+    /// Chat/peer ID the message was addressed to.
+    pub recipient: String,
+    /// Message text that failed to send.
+    pub body: String,
+    /// Platform-specific message ID to thread the reply under, if any.
+    pub reply_to: Option<String>,
+    /// Final error from the last delivery attempt.
+    pub error: String,
+    /// Number of delivery attempts made before parking.
+    pub attempts: u32,
+    pub created_at: i64,
+}
+
+/// Persistent queue of outbound sends that exhausted their retries.
+///
+/// Instead of losing a response with only a log line, the failed payload
+/// is parked here so an operator can inspect it and retry once the
+/// channel recovers.
+#[async_trait]
+pub trait DeadLetterQueue: Send + Sync {
+    /// Park a failed send. Returns the assigned dead-letter ID.
+    async fn push(&self, letter: DeadLetter) -> Result<i64>;
+
+    /// List parked messages, newest first. `account_id` filters to one
+    /// account when set.
+    async fn list(&self, account_id: Option<&str>, limit: u32) -> Result<Vec<DeadLetter>>;
+
+    /// Fetch a single parked message by ID.
+    async fn get(&self, id: i64) -> Result<Option<DeadLetter>>;
+
+    /// Remove a parked message (after successful retry or manual discard).
+    async fn remove(&self, id: i64) -> Result<()>;
+}
+
+/// Re-attempt delivery of a parked message through the channel's outbound.
+///
+/// On success the entry is removed from the queue; on failure it stays
+/// parked and the send error is returned.
+pub async fn retry_dead_letter(
+    queue: &dyn DeadLetterQueue,
+    outbound: &dyn ChannelOutbound,
+    id: i64,
+) -> Result<()> {
+    let letter = queue
+        .get(id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("dead letter not found: {id}"))?;
+
+    outbound
+        .send_text(
+            &letter.account_id,
+            &letter.recipient,
+            &letter.body,
+            letter.reply_to.as_deref(),
+        )
+        .await?;
+
+    queue.remove(id).await
+}
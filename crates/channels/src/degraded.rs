@@ -0,0 +1,51 @@
+use std::{
+    collections::HashSet,
+    sync::{Mutex, OnceLock},
+};
+
+use tracing::warn;
+
+/// Keys that have already produced a missing-dependency warning.
+static WARNED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+/// Warn (once per channel/account/dependency) that an optional plugin
+/// dependency is absent.
+///
+/// Plugins take `Option<Arc<dyn ChannelEventSink>>` and
+/// `Option<Arc<dyn MessageLog>>`; a misconfigured standalone plugin can
+/// end up with neither, silently dropping messages or audit records.
+/// Inbound processing must keep working in that case — this helper makes
+/// the degradation visible without spamming a warning per message.
+///
+/// Returns `true` when this call emitted the warning (first occurrence).
+pub fn warn_once_missing(channel: &str, account_id: &str, dependency: &str) -> bool {
+    let key = format!("{channel}:{account_id}:{dependency}");
+    let warned = WARNED.get_or_init(|| Mutex::new(HashSet::new()));
+    let mut warned = warned.lock().unwrap_or_else(|e| e.into_inner());
+    if !warned.insert(key) {
+        return false;
+    }
+    warn!(
+        channel,
+        account_id, dependency, "optional dependency not configured; running degraded"
+    );
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warns_only_once_per_key() {
+        assert!(warn_once_missing("test-chan", "acct-1", "event_sink"));
+        assert!(!warn_once_missing("test-chan", "acct-1", "event_sink"));
+    }
+
+    #[test]
+    fn distinct_keys_each_warn() {
+        assert!(warn_once_missing("test-chan", "acct-2", "event_sink"));
+        assert!(warn_once_missing("test-chan", "acct-2", "message_log"));
+        assert!(warn_once_missing("test-chan", "acct-3", "event_sink"));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for cross-channel duplicate-delivery suppression.
+///
+/// In bridged setups a single message can be mirrored into several
+/// channels (e.g. a bridge relaying the same text to two accounts),
+/// making the bot respond twice. Opt-in per deployment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct DedupeConfig {
+    /// Off by default — enable only for bridged deployments.
+    pub enabled: bool,
+    /// Window (seconds) within which identical text + peer is a duplicate.
+    pub window_secs: i64,
+}
+
+impl Default for DedupeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_secs: 30,
+        }
+    }
+}
+
+/// Detects near-duplicate inbound messages across channels.
+///
+/// A fingerprint is a hash of the normalized text (trimmed, whitespace
+/// collapsed, lowercased) plus the sender peer ID; a second message with
+/// the same fingerprint inside the window is reported as a duplicate and
+/// should be suppressed by the inbound path.
+pub struct MessageDeduplicator {
+    config: DedupeConfig,
+    seen: Mutex<HashMap<u64, i64>>,
+}
+
+impl MessageDeduplicator {
+    #[must_use]
+    pub fn new(config: DedupeConfig) -> Self {
+        Self {
+            config,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record a message and report whether it duplicates one already seen
+    /// within the window. `now` is unix seconds (passed in for testability).
+    pub fn observe(&self, peer_id: &str, text: &str, now: i64) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+
+        let fp = fingerprint(peer_id, text);
+        let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        seen.retain(|_, first_seen| now - *first_seen <= self.config.window_secs);
+        match seen.get(&fp) {
+            Some(first_seen) if now - first_seen <= self.config.window_secs => true,
+            _ => {
+                seen.insert(fp, now);
+                false
+            },
+        }
+    }
+
+    /// Record a message using the current wall-clock time.
+    pub fn observe_now(&self, peer_id: &str, text: &str) -> bool {
+        self.observe(peer_id, text, unix_now())
+    }
+}
+
+fn fingerprint(peer_id: &str, text: &str) -> u64 {
+    let normalized: String = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    let mut hasher = DefaultHasher::new();
+    peer_id.hash(&mut hasher);
+    normalized.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled(window_secs: i64) -> MessageDeduplicator {
+        MessageDeduplicator::new(DedupeConfig {
+            enabled: true,
+            window_secs,
+        })
+    }
+
+    #[test]
+    fn disabled_never_dedupes() {
+        let dedupe = MessageDeduplicator::new(DedupeConfig::default());
+        assert!(!dedupe.observe("alice", "hello", 100));
+        assert!(!dedupe.observe("alice", "hello", 100));
+    }
+
+    #[test]
+    fn identical_within_window_is_duplicate() {
+        let dedupe = enabled(30);
+        assert!(!dedupe.observe("alice", "hello world", 100));
+        assert!(dedupe.observe("alice", "hello world", 110));
+    }
+
+    #[test]
+    fn outside_window_both_processed() {
+        let dedupe = enabled(30);
+        assert!(!dedupe.observe("alice", "hello", 100));
+        assert!(!dedupe.observe("alice", "hello", 200));
+    }
+
+    #[test]
+    fn normalization_collapses_whitespace_and_case() {
+        let dedupe = enabled(30);
+        assert!(!dedupe.observe("alice", "Hello   World", 100));
+        assert!(dedupe.observe("alice", "hello world", 105));
+    }
+
+    #[test]
+    fn different_peer_is_not_duplicate() {
+        let dedupe = enabled(30);
+        assert!(!dedupe.observe("alice", "hello", 100));
+        assert!(!dedupe.observe("bob", "hello", 100));
+    }
+}
@@ -0,0 +1,211 @@
+use {
+    serde::{Deserialize, Serialize},
+    std::collections::HashMap,
+};
+
+/// Opt-in heuristic language detection for inbound channel text.
+///
+/// Multilingual deployments can route non-English messages to a
+/// translation-capable preset. Detection is script-based for non-Latin
+/// alphabets and stopword-based for Latin text — cheap and offline, not a
+/// full classifier; ambiguous text stays untagged rather than guessing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LanguageRouting {
+    /// Off by default.
+    pub enabled: bool,
+    /// Preset overrides keyed by detected ISO 639-1 code (e.g. "ru").
+    pub presets: HashMap<String, String>,
+}
+
+impl LanguageRouting {
+    /// Detect the language of inbound text. `None` when disabled or when
+    /// the heuristic is not confident.
+    #[must_use]
+    pub fn detect(&self, text: &str) -> Option<&'static str> {
+        if !self.enabled {
+            return None;
+        }
+        detect_language(text)
+    }
+
+    /// Preset configured for a detected language code, if any.
+    #[must_use]
+    pub fn preset_for(&self, code: &str) -> Option<&str> {
+        self.presets.get(code).map(String::as_str)
+    }
+}
+
+/// Minimum Latin stopword hits before a language is reported.
+const MIN_STOPWORD_HITS: usize = 2;
+
+/// Latin-script stopword sets, checked word-by-word.
+const LATIN_STOPWORDS: &[(&str, &[&str])] = &[
+    ("en", &[
+        "the", "and", "is", "are", "you", "what", "this", "for", "with",
+    ]),
+    ("es", &[
+        "el", "los", "una", "que", "es", "por", "como", "para", "está",
+    ]),
+    ("fr", &[
+        "les", "des", "est", "une", "pour", "vous", "avec", "dans", "c'est",
+    ]),
+    ("de", &[
+        "der", "die", "das", "und", "ist", "nicht", "ein", "mit", "ich",
+    ]),
+];
+
+/// Detect the dominant language of `text` by script, falling back to
+/// stopwords for Latin text. Returns an ISO 639-1 code or `None`.
+#[must_use]
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let mut cyrillic = 0usize;
+    let mut arabic = 0usize;
+    let mut hebrew = 0usize;
+    let mut greek = 0usize;
+    let mut devanagari = 0usize;
+    let mut kana = 0usize;
+    let mut hangul = 0usize;
+    let mut han = 0usize;
+    let mut latin = 0usize;
+
+    for c in text.chars() {
+        match c {
+            '\u{0400}'..='\u{04FF}' => cyrillic += 1,
+            '\u{0600}'..='\u{06FF}' => arabic += 1,
+            '\u{0590}'..='\u{05FF}' => hebrew += 1,
+            '\u{0370}'..='\u{03FF}' => greek += 1,
+            '\u{0900}'..='\u{097F}' => devanagari += 1,
+            '\u{3040}'..='\u{30FF}' => kana += 1,
+            '\u{AC00}'..='\u{D7AF}' => hangul += 1,
+            '\u{4E00}'..='\u{9FFF}' => han += 1,
+            'a'..='z' | 'A'..='Z' => latin += 1,
+            _ => {},
+        }
+    }
+
+    let scripts = [
+        ("ru", cyrillic),
+        ("ar", arabic),
+        ("he", hebrew),
+        ("el", greek),
+        ("hi", devanagari),
+        // Kana is unambiguously Japanese; bare Han defaults to Chinese.
+        ("ja", kana),
+        ("ko", hangul),
+        ("zh", han),
+    ];
+    let (code, count) = scripts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .unwrap_or(("ru", 0));
+    if count > 0 && count >= latin {
+        // Japanese mixes kana and han; any kana wins over han.
+        if code == "zh" && kana > 0 {
+            return Some("ja");
+        }
+        return Some(code);
+    }
+
+    if latin == 0 {
+        return None;
+    }
+    detect_latin_by_stopwords(text)
+}
+
+fn detect_latin_by_stopwords(text: &str) -> Option<&'static str> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let mut best: Option<(&'static str, usize)> = None;
+    let mut tied = false;
+    for (code, stopwords) in LATIN_STOPWORDS {
+        let hits = words.iter().filter(|w| stopwords.contains(*w)).count();
+        match best {
+            Some((_, top)) if hits == top => tied = true,
+            Some((_, top)) if hits > top => {
+                best = Some((code, hits));
+                tied = false;
+            },
+            None => best = Some((code, hits)),
+            _ => {},
+        }
+    }
+
+    match best {
+        Some((code, hits)) if hits >= MIN_STOPWORD_HITS && !tied => Some(code),
+        _ => None,
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routing(presets: &[(&str, &str)]) -> LanguageRouting {
+        LanguageRouting {
+            enabled: true,
+            presets: presets
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn disabled_routing_detects_nothing() {
+        let routing = LanguageRouting::default();
+        assert_eq!(routing.detect("Привет, как дела?"), None);
+    }
+
+    #[test]
+    fn cyrillic_text_is_russian() {
+        assert_eq!(detect_language("Привет, как дела?"), Some("ru"));
+    }
+
+    #[test]
+    fn kana_text_is_japanese() {
+        assert_eq!(detect_language("こんにちは、元気ですか"), Some("ja"));
+        // Han plus kana still reads as Japanese.
+        assert_eq!(detect_language("日本語を勉強しています"), Some("ja"));
+    }
+
+    #[test]
+    fn han_only_text_is_chinese() {
+        assert_eq!(detect_language("你好世界"), Some("zh"));
+    }
+
+    #[test]
+    fn spanish_stopwords_are_detected() {
+        assert_eq!(
+            detect_language("el tiempo es bueno por la mañana para todos"),
+            Some("es")
+        );
+    }
+
+    #[test]
+    fn english_stopwords_are_detected() {
+        assert_eq!(
+            detect_language("what is the weather like for tomorrow"),
+            Some("en")
+        );
+    }
+
+    #[test]
+    fn ambiguous_text_stays_untagged() {
+        assert_eq!(detect_language("ok"), None);
+        assert_eq!(detect_language("12345 !!!"), None);
+    }
+
+    #[test]
+    fn routing_selects_configured_preset() {
+        let routing = routing(&[("ru", "translator")]);
+        let code = routing.detect("Привет, как дела?").unwrap();
+        assert_eq!(routing.preset_for(code), Some("translator"));
+        assert_eq!(routing.preset_for("ja"), None);
+    }
+}
@@ -4,11 +4,29 @@
 //! ChannelPlugin trait with sub-traits for config, auth, inbound/outbound
 //! messaging, status, and gateway lifecycle.
 
+pub mod ack;
+pub mod audit;
+pub mod budget;
+pub mod cancellation;
+pub mod chat_type;
+pub mod command;
+pub mod concurrency;
+pub mod dead_letter;
+pub mod degraded;
+pub mod fingerprint;
 pub mod gating;
+pub mod injection_guard;
+pub mod language;
+pub mod media_pipeline;
+pub mod media_policy;
 pub mod message_log;
 pub mod plugin;
 pub mod registry;
+pub mod replay;
 pub mod store;
+pub mod template;
+pub mod tool_trace;
+pub mod truncation;
 
 pub use plugin::{
     ChannelAttachment, ChannelEvent, ChannelEventSink, ChannelHealthSnapshot, ChannelMessageKind,
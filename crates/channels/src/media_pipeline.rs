@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use {anyhow::Result, async_trait::async_trait};
+
+use crate::{
+    media_policy::{MediaKind, MediaPolicy},
+    plugin::ChannelAttachment,
+};
+
+/// A channel-specific attachment before normalization.
+///
+/// Plugins implement only the "fetch raw bytes" step (authed download,
+/// CDN fetch, OOB URL, decrypted blob) and hand the result here.
+#[derive(Debug, Clone)]
+pub struct RawAttachment {
+    /// MIME type as declared by the platform, if any.
+    pub declared_mime: Option<String>,
+    /// Original filename, if any.
+    pub filename: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// A normalized inbound attachment, policy-checked and MIME-resolved.
+#[derive(Debug, Clone)]
+pub struct MediaAttachment {
+    /// Sniffed (or declared) MIME type.
+    pub mime: String,
+    pub kind: MediaKind,
+    pub filename: Option<String>,
+    pub data: Vec<u8>,
+    /// URL of the rehosted copy when a [`MediaStore`] is configured.
+    pub rehosted_url: Option<String>,
+}
+
+impl MediaAttachment {
+    /// Convert to the multimodal dispatch shape.
+    #[must_use]
+    pub fn into_channel_attachment(self) -> ChannelAttachment {
+        ChannelAttachment {
+            media_type: self.mime,
+            data: self.data,
+        }
+    }
+}
+
+/// Optional rehosting target for normalized media (e.g. the session media
+/// directory or an object store). Returns the URL of the stored copy.
+#[async_trait]
+pub trait MediaStore: Send + Sync {
+    async fn store(&self, attachment: &MediaAttachment) -> Result<String>;
+}
+
+/// Shared inbound media normalization: MIME sniffing, policy enforcement,
+/// and optional rehosting, identical for every channel.
+#[derive(Default)]
+pub struct MediaPipeline {
+    policy: MediaPolicy,
+    store: Option<Arc<dyn MediaStore>>,
+}
+
+impl MediaPipeline {
+    #[must_use]
+    pub fn new(policy: MediaPolicy) -> Self {
+        Self {
+            policy,
+            store: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_store(mut self, store: Arc<dyn MediaStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Normalize a raw attachment: resolve the MIME type (magic bytes
+    /// first, declared type as fallback), apply the [`MediaPolicy`], and
+    /// rehost when a store is configured.
+    pub async fn normalize(&self, raw: RawAttachment) -> Result<MediaAttachment> {
+        let mime = sniff_mime(&raw.data)
+            .map(str::to_string)
+            .or(raw.declared_mime)
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        self.policy.check_raw(&mime, raw.data.len())?;
+
+        let mut attachment = MediaAttachment {
+            kind: MediaKind::from_mime(&mime),
+            mime,
+            filename: raw.filename,
+            data: raw.data,
+            rehosted_url: None,
+        };
+
+        if let Some(store) = &self.store {
+            attachment.rehosted_url = Some(store.store(&attachment).await?);
+        }
+
+        Ok(attachment)
+    }
+}
+
+/// Identify common formats by magic bytes; `None` when unrecognized.
+fn sniff_mime(data: &[u8]) -> Option<&'static str> {
+    match data {
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [0x89, b'P', b'N', b'G', ..] => Some("image/png"),
+        [b'G', b'I', b'F', b'8', ..] => Some("image/gif"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some("image/webp"),
+        [b'%', b'P', b'D', b'F', ..] => Some("application/pdf"),
+        [b'O', b'g', b'g', b'S', ..] => Some("audio/ogg"),
+        [b'I', b'D', b'3', ..] => Some("audio/mpeg"),
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => Some("video/mp4"),
+        _ => None,
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::sync::Mutex,
+    };
+
+    const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+    fn raw(data: &[u8], declared: Option<&str>) -> RawAttachment {
+        RawAttachment {
+            declared_mime: declared.map(str::to_string),
+            filename: Some("file.bin".into()),
+            data: data.to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn sniffs_mime_over_declared_type() {
+        let pipeline = MediaPipeline::default();
+        let out = pipeline
+            .normalize(raw(PNG, Some("application/octet-stream")))
+            .await
+            .unwrap();
+        assert_eq!(out.mime, "image/png");
+        assert_eq!(out.kind, MediaKind::Image);
+        assert!(out.rehosted_url.is_none());
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_declared_then_octet_stream() {
+        let pipeline = MediaPipeline::default();
+        let declared = pipeline
+            .normalize(raw(b"plain text", Some("text/plain")))
+            .await
+            .unwrap();
+        assert_eq!(declared.mime, "text/plain");
+
+        let unknown = pipeline.normalize(raw(b"plain text", None)).await.unwrap();
+        assert_eq!(unknown.mime, "application/octet-stream");
+    }
+
+    #[tokio::test]
+    async fn applies_policy_to_sniffed_type() {
+        let pipeline = MediaPipeline::new(MediaPolicy {
+            blocked_mime_types: vec!["image/*".into()],
+            ..Default::default()
+        });
+        let err = pipeline.normalize(raw(PNG, None)).await.unwrap_err();
+        assert!(err.to_string().contains("blocked"));
+    }
+
+    struct RecordingStore {
+        stored: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl MediaStore for RecordingStore {
+        async fn store(&self, attachment: &MediaAttachment) -> Result<String> {
+            self.stored
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(attachment.mime.clone());
+            Ok("media://stored/1".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn rehosts_when_store_configured() {
+        let store = Arc::new(RecordingStore {
+            stored: Mutex::new(Vec::new()),
+        });
+        let pipeline = MediaPipeline::default().with_store(Arc::clone(&store) as _);
+        let out = pipeline.normalize(raw(PNG, None)).await.unwrap();
+        assert_eq!(out.rehosted_url.as_deref(), Some("media://stored/1"));
+        assert_eq!(
+            store.stored.lock().unwrap().as_slice(),
+            ["image/png".to_string()]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use {
+    serde::{Deserialize, Serialize},
+    thiserror::Error,
+};
+
+use crate::plugin::ChannelAttachment;
+
+/// Coarse media kind derived from a MIME type, used for per-kind caps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaKind {
+    Image,
+    Audio,
+    Video,
+    Document,
+}
+
+impl MediaKind {
+    /// Infer the media kind from a MIME type (e.g. "image/png" → Image).
+    pub fn from_mime(mime: &str) -> Self {
+        let top = mime.split('/').next().unwrap_or_default();
+        match top.to_ascii_lowercase().as_str() {
+            "image" => Self::Image,
+            "audio" => Self::Audio,
+            "video" => Self::Video,
+            _ => Self::Document,
+        }
+    }
+}
+
+/// Why an attachment was rejected by the media policy.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MediaRejected {
+    #[error("attachment of {size} bytes exceeds the {limit} byte limit")]
+    TooLarge { size: usize, limit: usize },
+
+    #[error("media type '{mime}' is blocked by policy")]
+    BlockedType { mime: String },
+
+    #[error("media type '{mime}' is not on the allowed list")]
+    NotAllowed { mime: String },
+}
+
+/// Shared inbound attachment policy applied uniformly across channels.
+///
+/// Each channel plugin consults one policy instead of growing its own
+/// `media_max_mb` / blocked-type fields, so limits stay consistent and
+/// configurable in one place. An empty allow list means all types are
+/// allowed (subject to the block list); MIME entries are matched
+/// case-insensitively and support a trailing `/*` wildcard
+/// (e.g. "image/*").
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MediaPolicy {
+    /// Maximum attachment size in megabytes. `None` disables the global cap.
+    pub max_mb: Option<u64>,
+
+    /// MIME types (or `type/*` wildcards) that are always rejected.
+    pub blocked_mime_types: Vec<String>,
+
+    /// When non-empty, only these MIME types (or wildcards) are accepted.
+    pub allowed_mime_types: Vec<String>,
+
+    /// Per-kind size caps in megabytes, overriding `max_mb` for that kind.
+    pub per_kind_max_mb: HashMap<MediaKind, u64>,
+}
+
+const BYTES_PER_MB: usize = 1024 * 1024;
+
+impl MediaPolicy {
+    /// Check an inbound attachment against the policy.
+    pub fn check(&self, attachment: &ChannelAttachment) -> Result<(), MediaRejected> {
+        self.check_raw(&attachment.media_type, attachment.data.len())
+    }
+
+    /// Check a MIME type + size pair without materializing the bytes
+    /// (useful when the size is known from metadata before download).
+    pub fn check_raw(&self, mime: &str, size_bytes: usize) -> Result<(), MediaRejected> {
+        let mime_lower = mime.to_ascii_lowercase();
+
+        if Self::matches_any(&mime_lower, &self.blocked_mime_types) {
+            return Err(MediaRejected::BlockedType { mime: mime_lower });
+        }
+
+        if !self.allowed_mime_types.is_empty()
+            && !Self::matches_any(&mime_lower, &self.allowed_mime_types)
+        {
+            return Err(MediaRejected::NotAllowed { mime: mime_lower });
+        }
+
+        let kind = MediaKind::from_mime(&mime_lower);
+        let cap_mb = self.per_kind_max_mb.get(&kind).copied().or(self.max_mb);
+        if let Some(mb) = cap_mb {
+            let limit = (mb as usize).saturating_mul(BYTES_PER_MB);
+            if size_bytes > limit {
+                return Err(MediaRejected::TooLarge {
+                    size: size_bytes,
+                    limit,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn matches_any(mime: &str, patterns: &[String]) -> bool {
+        patterns.iter().any(|p| {
+            let pat = p.to_ascii_lowercase();
+            if let Some(prefix) = pat.strip_suffix("/*") {
+                mime.split('/').next() == Some(prefix)
+            } else {
+                pat == mime
+            }
+        })
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(mime: &str, size: usize) -> ChannelAttachment {
+        ChannelAttachment {
+            media_type: mime.into(),
+            data: vec![0u8; size],
+        }
+    }
+
+    #[test]
+    fn default_policy_allows_everything() {
+        let policy = MediaPolicy::default();
+        assert!(policy.check(&attachment("image/png", 10 * BYTES_PER_MB)).is_ok());
+    }
+
+    #[test]
+    fn size_cap_rejects_oversized() {
+        let policy = MediaPolicy {
+            max_mb: Some(1),
+            ..Default::default()
+        };
+        let err = policy
+            .check(&attachment("image/jpeg", 2 * BYTES_PER_MB))
+            .unwrap_err();
+        assert!(matches!(err, MediaRejected::TooLarge { .. }));
+    }
+
+    #[test]
+    fn blocked_type_rejected() {
+        let policy = MediaPolicy {
+            blocked_mime_types: vec!["application/x-executable".into()],
+            ..Default::default()
+        };
+        let err = policy
+            .check(&attachment("application/X-Executable", 100))
+            .unwrap_err();
+        assert!(matches!(err, MediaRejected::BlockedType { .. }));
+    }
+
+    #[test]
+    fn blocked_wildcard_rejects_whole_type() {
+        let policy = MediaPolicy {
+            blocked_mime_types: vec!["video/*".into()],
+            ..Default::default()
+        };
+        assert!(policy.check(&attachment("video/mp4", 100)).is_err());
+        assert!(policy.check(&attachment("image/png", 100)).is_ok());
+    }
+
+    #[test]
+    fn allowlist_restricts_types() {
+        let policy = MediaPolicy {
+            allowed_mime_types: vec!["image/*".into()],
+            ..Default::default()
+        };
+        assert!(policy.check(&attachment("image/webp", 100)).is_ok());
+        let err = policy.check(&attachment("application/pdf", 100)).unwrap_err();
+        assert!(matches!(err, MediaRejected::NotAllowed { .. }));
+    }
+
+    #[test]
+    fn per_kind_cap_overrides_global() {
+        let policy = MediaPolicy {
+            max_mb: Some(100),
+            per_kind_max_mb: HashMap::from([(MediaKind::Image, 1)]),
+            ..Default::default()
+        };
+        // Images get the tighter per-kind cap.
+        assert!(policy.check(&attachment("image/png", 2 * BYTES_PER_MB)).is_err());
+        // Documents fall back to the global cap.
+        assert!(
+            policy
+                .check(&attachment("application/pdf", 2 * BYTES_PER_MB))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn allowed_attachment_passes() {
+        let policy = MediaPolicy {
+            max_mb: Some(5),
+            blocked_mime_types: vec!["application/x-executable".into()],
+            allowed_mime_types: vec![],
+            per_kind_max_mb: HashMap::new(),
+        };
+        assert!(policy.check(&attachment("image/png", BYTES_PER_MB)).is_ok());
+    }
+
+    #[test]
+    fn kind_inference() {
+        assert_eq!(MediaKind::from_mime("image/png"), MediaKind::Image);
+        assert_eq!(MediaKind::from_mime("AUDIO/ogg"), MediaKind::Audio);
+        assert_eq!(MediaKind::from_mime("video/mp4"), MediaKind::Video);
+        assert_eq!(MediaKind::from_mime("text/plain"), MediaKind::Document);
+    }
+}
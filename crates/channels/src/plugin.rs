@@ -174,6 +174,10 @@ pub struct ChannelMessageMeta {
     /// Default model configured for this channel account.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+    /// Detected inbound language (ISO 639-1), when language routing is
+    /// enabled for the account (see [`crate::language::LanguageRouting`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 /// Inbound channel message media kind.
@@ -221,6 +225,15 @@ pub trait ChannelPlugin: Send + Sync {
     /// Human-readable channel name.
     fn name(&self) -> &str;
 
+    /// Validate an account config without side effects.
+    ///
+    /// Checks shape and invariants (required fields, non-empty token/JID)
+    /// so callers can reject bad config cheaply before attempting a
+    /// connection. `start_account` must call this first.
+    fn validate_config(&self, _config: &serde_json::Value) -> Result<()> {
+        Ok(())
+    }
+
     /// Start an account connection.
     async fn start_account(&mut self, account_id: &str, config: serde_json::Value) -> Result<()>;
 
@@ -254,6 +267,21 @@ pub trait ChannelOutbound: Send + Sync {
         payload: &ReplyPayload,
         reply_to: Option<&str>,
     ) -> Result<()>;
+    /// Send raw bytes as a file attachment (e.g. a locally generated chart
+    /// with no hostable URL). Channels without native file upload keep the
+    /// default, which errors.
+    async fn send_file(
+        &self,
+        account_id: &str,
+        to: &str,
+        filename: &str,
+        bytes: Vec<u8>,
+        mime: &str,
+        caption: Option<&str>,
+    ) -> Result<()> {
+        let _ = (account_id, to, filename, bytes, mime, caption);
+        anyhow::bail!("file upload not supported on this channel")
+    }
     /// Send a "typing" indicator. No-op by default.
     async fn send_typing(&self, _account_id: &str, _to: &str) -> Result<()> {
         Ok(())
@@ -311,11 +339,74 @@ pub trait ChannelStatus: Send + Sync {
 }
 
 /// Channel health snapshot.
-#[derive(Debug, Clone)]
+///
+/// `details` stays a free-text summary for backward compatibility; the
+/// structured fields let UIs render connectivity state without parsing it.
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ChannelHealthSnapshot {
     pub connected: bool,
     pub account_id: String,
     pub details: Option<String>,
+    /// Unix timestamp of the last successful probe/connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_connected_at: Option<i64>,
+    /// Most recent error observed for this account, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// Number of reconnect attempts since the last successful connection.
+    pub reconnect_attempts: u32,
+    /// Round-trip latency of the last health probe, when measured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+}
+
+impl ChannelHealthSnapshot {
+    /// Snapshot for a healthy, connected account.
+    pub fn connected(account_id: impl Into<String>, details: Option<String>) -> Self {
+        Self {
+            connected: true,
+            account_id: account_id.into(),
+            details,
+            last_connected_at: Some(unix_now()),
+            last_error: None,
+            reconnect_attempts: 0,
+            latency_ms: None,
+        }
+    }
+
+    /// Snapshot for a disconnected or errored account.
+    pub fn disconnected(account_id: impl Into<String>, error: Option<String>) -> Self {
+        Self {
+            connected: false,
+            account_id: account_id.into(),
+            details: error.clone(),
+            last_connected_at: None,
+            last_error: error,
+            reconnect_attempts: 0,
+            latency_ms: None,
+        }
+    }
+
+    /// Attach a measured probe latency.
+    #[must_use]
+    pub fn with_latency_ms(mut self, latency_ms: u64) -> Self {
+        self.latency_ms = Some(latency_ms);
+        self
+    }
+
+    /// Attach the reconnect attempt counter.
+    #[must_use]
+    pub fn with_reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.reconnect_attempts = attempts;
+        self
+    }
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
 }
 
 /// Stream event for edit-in-place streaming.
@@ -353,6 +444,7 @@ pub trait ChannelStreamOutbound: Send + Sync {
     }
 }
 
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -431,6 +523,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn health_snapshot_connected_fills_structured_fields() {
+        let snap = ChannelHealthSnapshot::connected("acct", Some("Bot: @test".into()))
+            .with_latency_ms(12);
+        assert!(snap.connected);
+        assert_eq!(snap.account_id, "acct");
+        assert!(snap.last_connected_at.is_some());
+        assert!(snap.last_error.is_none());
+        assert_eq!(snap.latency_ms, Some(12));
+        assert_eq!(snap.reconnect_attempts, 0);
+    }
+
+    #[test]
+    fn health_snapshot_disconnected_records_error() {
+        let snap = ChannelHealthSnapshot::disconnected("acct", Some("API error: boom".into()))
+            .with_reconnect_attempts(3);
+        assert!(!snap.connected);
+        assert_eq!(snap.last_error.as_deref(), Some("API error: boom"));
+        assert_eq!(snap.details.as_deref(), Some("API error: boom"));
+        assert!(snap.last_connected_at.is_none());
+        assert_eq!(snap.reconnect_attempts, 3);
+    }
+
     #[tokio::test]
     async fn default_send_location_is_noop() {
         let out = DummyOutbound;
@@ -439,4 +554,21 @@ mod tests {
             .await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn default_send_file_is_unsupported() {
+        let out = DummyOutbound;
+        let err = out
+            .send_file(
+                "acct",
+                "42",
+                "chart.png",
+                vec![1, 2, 3],
+                "image/png",
+                Some("a chart"),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not supported"));
+    }
 }
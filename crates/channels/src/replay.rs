@@ -0,0 +1,228 @@
+use {
+    crate::{
+        gating::{is_allowed, DmPolicy, GroupPolicy},
+        plugin::{
+            ChannelEvent, ChannelEventSink, ChannelMessageMeta, ChannelReplyTarget, ChannelType,
+        },
+    },
+    anyhow::Result,
+    moltis_common::types::ChatType,
+    serde::{Deserialize, Serialize},
+};
+
+/// One recorded inbound message in a replay fixture.
+///
+/// Fixtures are a JSON array of these objects, typically exported from the
+/// message log or written by hand to reproduce a reported bug.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    pub account_id: String,
+    /// Sender peer ID (user ID or group/chat ID, matching the allowlists).
+    pub peer_id: String,
+    pub chat_type: ChatType,
+    pub text: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub sender_name: Option<String>,
+}
+
+/// Gating configuration applied during replay — the same policies a live
+/// plugin evaluates per inbound message.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayGating {
+    pub dm_policy: DmPolicy,
+    pub group_policy: GroupPolicy,
+    pub allowlist: Vec<String>,
+    pub group_allowlist: Vec<String>,
+}
+
+impl ReplayGating {
+    fn allows(&self, event: &ReplayEvent) -> bool {
+        match event.chat_type {
+            ChatType::Dm => match self.dm_policy {
+                DmPolicy::Open => true,
+                DmPolicy::Allowlist => is_allowed(&event.peer_id, &self.allowlist),
+                DmPolicy::Disabled => false,
+            },
+            ChatType::Group | ChatType::Channel => match self.group_policy {
+                GroupPolicy::Open => true,
+                GroupPolicy::Allowlist => is_allowed(&event.peer_id, &self.group_allowlist),
+                GroupPolicy::Disabled => false,
+            },
+        }
+    }
+}
+
+/// Summary of a replay run.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    /// Messages that passed gating and were dispatched to the chat session.
+    pub dispatched: usize,
+    /// Messages denied by gating.
+    pub denied: usize,
+}
+
+/// Parse a JSON fixture (an array of [`ReplayEvent`]s).
+pub fn parse_fixture(json: &str) -> Result<Vec<ReplayEvent>> {
+    Ok(serde_json::from_str(json)?)
+}
+
+/// Drive recorded inbound events through the gating + event-sink path
+/// without a live connection.
+///
+/// Each event is evaluated against `gating` exactly as the live inbound
+/// path would: an `InboundMessage` channel event is emitted with the
+/// gating outcome, and only allowed messages are dispatched to the chat
+/// session. Deterministic by construction, so reported bugs can be
+/// reproduced from a fixture.
+pub async fn replay_events(
+    channel_type: ChannelType,
+    gating: &ReplayGating,
+    sink: &dyn ChannelEventSink,
+    events: Vec<ReplayEvent>,
+) -> ReplayReport {
+    let mut report = ReplayReport::default();
+    for event in events {
+        let access_granted = gating.allows(&event);
+        sink.emit(ChannelEvent::InboundMessage {
+            channel_type,
+            account_id: event.account_id.clone(),
+            peer_id: event.peer_id.clone(),
+            username: event.username.clone(),
+            sender_name: event.sender_name.clone(),
+            message_count: None,
+            access_granted,
+        })
+        .await;
+
+        if !access_granted {
+            report.denied += 1;
+            continue;
+        }
+
+        let reply_to = ChannelReplyTarget {
+            channel_type,
+            account_id: event.account_id.clone(),
+            chat_id: event.peer_id.clone(),
+            message_id: None,
+        };
+        let meta = ChannelMessageMeta {
+            channel_type,
+            sender_name: event.sender_name.clone(),
+            username: event.username.clone(),
+            message_kind: None,
+            model: None,
+            language: None,
+        };
+        sink.dispatch_to_chat(&event.text, reply_to, meta).await;
+        report.dispatched += 1;
+    }
+    report
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        async_trait::async_trait,
+        std::sync::{Arc, Mutex},
+    };
+
+    #[derive(Default)]
+    struct RecordingSink {
+        emitted: Arc<Mutex<Vec<ChannelEvent>>>,
+        dispatched: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl ChannelEventSink for RecordingSink {
+        async fn emit(&self, event: ChannelEvent) {
+            self.emitted
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(event);
+        }
+
+        async fn dispatch_to_chat(
+            &self,
+            text: &str,
+            _reply_to: ChannelReplyTarget,
+            _meta: ChannelMessageMeta,
+        ) {
+            self.dispatched
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(text.to_string());
+        }
+
+        async fn dispatch_command(
+            &self,
+            _command: &str,
+            _reply_to: ChannelReplyTarget,
+        ) -> Result<String> {
+            Ok(String::new())
+        }
+
+        async fn request_disable_account(
+            &self,
+            _channel_type: &str,
+            _account_id: &str,
+            _reason: &str,
+        ) {
+        }
+    }
+
+    const FIXTURE: &str = r#"[
+        {
+            "account_id": "acct",
+            "peer_id": "alice",
+            "chat_type": "dm",
+            "text": "hello"
+        },
+        {
+            "account_id": "acct",
+            "peer_id": "mallory",
+            "chat_type": "dm",
+            "text": "hi there"
+        }
+    ]"#;
+
+    #[tokio::test]
+    async fn replays_fixture_through_gating() {
+        let events = parse_fixture(FIXTURE).unwrap();
+        let gating = ReplayGating {
+            dm_policy: DmPolicy::Allowlist,
+            allowlist: vec!["alice".into()],
+            ..Default::default()
+        };
+        let sink = RecordingSink::default();
+
+        let report = replay_events(ChannelType::Telegram, &gating, &sink, events).await;
+        assert_eq!(report, ReplayReport {
+            dispatched: 1,
+            denied: 1,
+        });
+
+        let dispatched = sink.dispatched.lock().unwrap();
+        assert_eq!(dispatched.as_slice(), ["hello"]);
+
+        let emitted = sink.emitted.lock().unwrap();
+        assert_eq!(emitted.len(), 2);
+        let granted: Vec<bool> = emitted
+            .iter()
+            .map(|e| match e {
+                ChannelEvent::InboundMessage { access_granted, .. } => *access_granted,
+                _ => panic!("unexpected event"),
+            })
+            .collect();
+        assert_eq!(granted, [true, false]);
+    }
+
+    #[test]
+    fn parse_fixture_rejects_bad_json() {
+        assert!(parse_fixture("not json").is_err());
+    }
+}
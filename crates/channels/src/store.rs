@@ -1,5 +1,50 @@
 use {anyhow::Result, async_trait::async_trait, serde::Serialize};
 
+/// One config migration step, upgrading a stored account config from
+/// version `i` to `i + 1`. Steps must be idempotent field rewrites
+/// (fill renamed/removed fields with their new shape).
+pub type ConfigMigrationStep = fn(&mut serde_json::Value);
+
+/// Upgrade a stored account config to the channel's current version.
+///
+/// The version is carried in a `config_version` field inside the config
+/// JSON (absent means version 0); the channel's current version is the
+/// number of migration steps it defines. Returns `true` when the config
+/// was changed and should be re-persisted. Configs stamped with a future
+/// version error clearly instead of silently misparsing.
+pub fn migrate_config(
+    config: &mut serde_json::Value,
+    steps: &[ConfigMigrationStep],
+) -> Result<bool> {
+    let current = steps.len() as u64;
+    let version = config
+        .get("config_version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0);
+    if version > current {
+        anyhow::bail!(
+            "stored config_version {version} is newer than supported version {current}; \
+             refusing to load"
+        );
+    }
+    if version == current {
+        return Ok(false);
+    }
+    if !config.is_object() {
+        anyhow::bail!("stored config is not a JSON object; refusing to migrate");
+    }
+    for step in &steps[version as usize..] {
+        step(config);
+    }
+    if current > 0 {
+        let Some(obj) = config.as_object_mut() else {
+            anyhow::bail!("config migration step replaced the config with a non-object value");
+        };
+        obj.insert("config_version".to_string(), current.into());
+    }
+    Ok(version < current)
+}
+
 /// A persisted channel configuration.
 #[derive(Debug, Clone, Serialize)]
 pub struct StoredChannel {
@@ -18,3 +63,56 @@ pub trait ChannelStore: Send + Sync {
     async fn upsert(&self, channel: StoredChannel) -> Result<()>;
     async fn delete(&self, account_id: &str) -> Result<()>;
 }
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v0_to_v1(config: &mut serde_json::Value) {
+        // Example rename: legacy `allow` list becomes `allowlist`.
+        if let Some(legacy) = config.get("allow").cloned() {
+            config["allowlist"] = legacy;
+            config.as_object_mut().unwrap().remove("allow");
+        }
+    }
+
+    const STEPS: &[ConfigMigrationStep] = &[v0_to_v1];
+
+    #[test]
+    fn migrates_v0_config_and_stamps_version() {
+        let mut config = serde_json::json!({ "token": "t", "allow": ["alice"] });
+        let changed = migrate_config(&mut config, STEPS).unwrap();
+        assert!(changed);
+        assert_eq!(config["allowlist"], serde_json::json!(["alice"]));
+        assert!(config.get("allow").is_none());
+        assert_eq!(config["config_version"], 1);
+    }
+
+    #[test]
+    fn current_version_is_a_no_op() {
+        let mut config = serde_json::json!({ "token": "t", "config_version": 1 });
+        let changed = migrate_config(&mut config, STEPS).unwrap();
+        assert!(!changed);
+    }
+
+    #[test]
+    fn future_version_errors_clearly() {
+        let mut config = serde_json::json!({ "token": "t", "config_version": 9 });
+        let err = migrate_config(&mut config, STEPS).unwrap_err();
+        assert!(err.to_string().contains("newer than supported"));
+    }
+
+    #[test]
+    fn non_object_config_errors_instead_of_panicking() {
+        let mut config = serde_json::json!("not an object");
+        let err = migrate_config(&mut config, STEPS).unwrap_err();
+        assert!(err.to_string().contains("not a JSON object"));
+    }
+
+    #[test]
+    fn no_steps_accepts_unversioned_config() {
+        let mut config = serde_json::json!({ "token": "t" });
+        assert!(!migrate_config(&mut config, &[]).unwrap());
+    }
+}
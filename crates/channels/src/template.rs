@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+/// Opt-in wrapper applied to agent responses before channel-specific
+/// formatting.
+///
+/// The template contains `{agent}`, `{response}`, and `{footer}`
+/// placeholders. Because long responses are chunked per channel, the part
+/// of the template before `{response}` is prepended only to the first
+/// chunk and the part after it appended only to the last chunk, so a
+/// footer never appears mid-response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct OutboundTemplate {
+    /// Off by default.
+    pub enabled: bool,
+    /// Template with `{agent}`/`{response}`/`{footer}` placeholders.
+    pub template: String,
+    /// Value substituted for `{agent}`.
+    pub agent: String,
+    /// Value substituted for `{footer}`.
+    pub footer: String,
+}
+
+impl Default for OutboundTemplate {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            template: "{response}".to_string(),
+            agent: String::new(),
+            footer: String::new(),
+        }
+    }
+}
+
+impl OutboundTemplate {
+    /// Apply the template across an already-chunked response.
+    #[must_use]
+    pub fn apply(&self, chunks: Vec<String>) -> Vec<String> {
+        if !self.enabled || chunks.is_empty() {
+            return chunks;
+        }
+
+        let (prefix, suffix) = match self.template.split_once("{response}") {
+            Some((p, s)) => (p, s),
+            // Without a {response} placeholder there is nothing to wrap.
+            None => return chunks,
+        };
+        let prefix = self.expand(prefix);
+        let suffix = self.expand(suffix);
+
+        let last = chunks.len() - 1;
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                let mut out = String::new();
+                if i == 0 {
+                    out.push_str(&prefix);
+                }
+                out.push_str(&chunk);
+                if i == last {
+                    out.push_str(&suffix);
+                }
+                out
+            })
+            .collect()
+    }
+
+    fn expand(&self, part: &str) -> String {
+        part.replace("{agent}", &self.agent)
+            .replace("{footer}", &self.footer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> OutboundTemplate {
+        OutboundTemplate {
+            enabled: true,
+            template: "{agent}: {response}\n— {footer}".to_string(),
+            agent: "Moltis".to_string(),
+            footer: "sent via moltis".to_string(),
+        }
+    }
+
+    #[test]
+    fn disabled_template_passes_through() {
+        let chunks = vec!["hello".to_string()];
+        assert_eq!(OutboundTemplate::default().apply(chunks.clone()), chunks);
+    }
+
+    #[test]
+    fn single_chunk_gets_prefix_and_footer() {
+        let out = template().apply(vec!["hello".to_string()]);
+        assert_eq!(out, ["Moltis: hello\n— sent via moltis"]);
+    }
+
+    #[test]
+    fn multi_chunk_prefix_first_footer_last() {
+        let out = template().apply(vec!["one".into(), "two".into(), "three".into()]);
+        assert_eq!(out, [
+            "Moltis: one",
+            "two",
+            "three\n— sent via moltis"
+        ]);
+    }
+
+    #[test]
+    fn template_without_response_placeholder_is_ignored() {
+        let mut t = template();
+        t.template = "{agent} says things".to_string();
+        let chunks = vec!["hello".to_string()];
+        assert_eq!(t.apply(chunks.clone()), chunks);
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+/// How tool-call events are surfaced in a channel while the agent works.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolTraceMode {
+    /// Don't surface tool calls (default).
+    #[default]
+    Hidden,
+    /// One-line summary: tool name plus truncated arguments.
+    Compact,
+    /// Tool name with full arguments.
+    Full,
+}
+
+/// Configuration for rendering agent tool-call traces into channel messages.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ToolTraceConfig {
+    pub mode: ToolTraceMode,
+    /// Max argument characters shown in compact mode before truncation.
+    pub max_args_chars: usize,
+}
+
+impl Default for ToolTraceConfig {
+    fn default() -> Self {
+        Self {
+            mode: ToolTraceMode::Hidden,
+            max_args_chars: 80,
+        }
+    }
+}
+
+impl ToolTraceConfig {
+    /// Render a tool-call event into a channel message, or `None` when
+    /// traces are suppressed. Plaintext output — channel plugins wrap it
+    /// in their native rich format if they have one.
+    #[must_use]
+    pub fn render(&self, tool_name: &str, arguments: &str) -> Option<String> {
+        match self.mode {
+            ToolTraceMode::Hidden => None,
+            ToolTraceMode::Compact => {
+                let args: String = if arguments.chars().count() > self.max_args_chars {
+                    let kept: String = arguments.chars().take(self.max_args_chars).collect();
+                    format!("{kept}…")
+                } else {
+                    arguments.to_string()
+                };
+                Some(trace_line(tool_name, &args))
+            },
+            ToolTraceMode::Full => Some(trace_line(tool_name, arguments)),
+        }
+    }
+}
+
+fn trace_line(tool_name: &str, args: &str) -> String {
+    if args.is_empty() {
+        format!("🔧 ran {tool_name}")
+    } else {
+        format!("🔧 ran {tool_name}: {args}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hidden_suppresses_output() {
+        let config = ToolTraceConfig::default();
+        assert!(config.render("exec", "ls").is_none());
+    }
+
+    #[test]
+    fn compact_renders_one_line() {
+        let config = ToolTraceConfig {
+            mode: ToolTraceMode::Compact,
+            ..Default::default()
+        };
+        assert_eq!(config.render("exec", "ls").as_deref(), Some("🔧 ran exec: ls"));
+        assert_eq!(config.render("exec", "").as_deref(), Some("🔧 ran exec"));
+    }
+
+    #[test]
+    fn compact_truncates_long_arguments() {
+        let config = ToolTraceConfig {
+            mode: ToolTraceMode::Compact,
+            max_args_chars: 5,
+        };
+        assert_eq!(
+            config.render("exec", "ls -la /tmp").as_deref(),
+            Some("🔧 ran exec: ls -l…")
+        );
+    }
+
+    #[test]
+    fn full_keeps_arguments_intact() {
+        let config = ToolTraceConfig {
+            mode: ToolTraceMode::Full,
+            max_args_chars: 5,
+        };
+        assert_eq!(
+            config.render("exec", "ls -la /tmp").as_deref(),
+            Some("🔧 ran exec: ls -la /tmp")
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// Policy for truncating very long inbound messages before the agent turn.
+///
+/// Pasted logs and similar walls of text can blow past model context and
+/// cost. When enabled, text above `max_chars` is cut (on a char boundary)
+/// and a continuation notice is appended so the model knows content is
+/// missing. The full original text must still be written to the message
+/// log — apply this only to the turn input, after logging.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct InboundTruncation {
+    /// Off by default — opt-in per account.
+    pub enabled: bool,
+    /// Maximum characters passed to the agent turn.
+    pub max_chars: usize,
+}
+
+impl Default for InboundTruncation {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_chars: 16_000,
+        }
+    }
+}
+
+impl InboundTruncation {
+    /// Apply the policy, returning the (possibly truncated) turn input.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !self.enabled || text.chars().count() <= self.max_chars {
+            return Cow::Borrowed(text);
+        }
+
+        let kept: String = text.chars().take(self.max_chars).collect();
+        let dropped = text.chars().count() - self.max_chars;
+        Cow::Owned(format!("{kept}\n[truncated {dropped} chars]"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_policy_passes_through() {
+        let policy = InboundTruncation::default();
+        let long = "x".repeat(100_000);
+        assert!(matches!(policy.apply(&long), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn short_text_is_untouched() {
+        let policy = InboundTruncation {
+            enabled: true,
+            max_chars: 100,
+        };
+        assert!(matches!(policy.apply("hello"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn long_text_gets_marker() {
+        let policy = InboundTruncation {
+            enabled: true,
+            max_chars: 10,
+        };
+        let out = policy.apply("abcdefghijklmnop");
+        assert_eq!(out.as_ref(), "abcdefghij\n[truncated 6 chars]");
+    }
+
+    #[test]
+    fn truncates_on_char_boundary() {
+        let policy = InboundTruncation {
+            enabled: true,
+            max_chars: 2,
+        };
+        let out = policy.apply("héllo");
+        assert!(out.starts_with("hé"));
+        assert!(out.contains("[truncated 3 chars]"));
+    }
+}
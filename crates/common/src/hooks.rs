@@ -207,6 +207,9 @@ impl HookPayload {
 
 // ── HookAction ──────────────────────────────────────────────────────────────
 
+/// Max re-invocations honored when a handler returns [`HookAction::Retry`].
+const MAX_HOOK_RETRIES: u32 = 2;
+
 /// The outcome a hook handler returns.
 #[derive(Debug, Default)]
 pub enum HookAction {
@@ -217,6 +220,10 @@ pub enum HookAction {
     ModifyPayload(Value),
     /// Block the action entirely, with a reason string.
     Block(String),
+    /// Transient failure — re-invoke this handler after the given delay.
+    /// Resolved inside the registry (bounded by [`MAX_HOOK_RETRIES`]);
+    /// `dispatch` never returns it to callers.
+    Retry { after: Duration },
 }
 
 // ── HookHandler trait ───────────────────────────────────────────────────────
@@ -470,6 +477,39 @@ impl HookRegistry {
         }
     }
 
+    /// Invoke a handler, honoring bounded [`HookAction::Retry`] responses.
+    /// A handler still asking to retry after [`MAX_HOOK_RETRIES`]
+    /// re-invocations is treated as `Continue`.
+    async fn handle_with_retry(
+        handler: &dyn HookHandler,
+        event: HookEvent,
+        payload: &HookPayload,
+    ) -> Result<HookAction> {
+        let mut attempt = 0u32;
+        loop {
+            match handler.handle(event, payload).await? {
+                HookAction::Retry { after } if attempt < MAX_HOOK_RETRIES => {
+                    attempt += 1;
+                    debug!(
+                        handler = handler.name(),
+                        ?after,
+                        attempt,
+                        "hook requested retry"
+                    );
+                    tokio::time::sleep(after).await;
+                },
+                HookAction::Retry { .. } => {
+                    warn!(
+                        handler = handler.name(),
+                        "hook still retrying after {MAX_HOOK_RETRIES} attempts, continuing"
+                    );
+                    return Ok(HookAction::Continue);
+                },
+                action => return Ok(action),
+            }
+        }
+    }
+
     /// Dispatch handlers in parallel. Block/Modify actions are logged but
     /// don't affect the event flow (read-only events are informational).
     async fn dispatch_parallel(
@@ -488,7 +528,7 @@ impl HookRegistry {
             let payload = payload.clone();
             futures.push(async move {
                 let start = Instant::now();
-                let result = handler.handle(event, &payload).await;
+                let result = Self::handle_with_retry(handler.as_ref(), event, &payload).await;
                 let latency = start.elapsed();
                 match &result {
                     Ok(_) => stats.record_success(latency),
@@ -508,6 +548,8 @@ impl HookRegistry {
                 Ok(HookAction::Block(reason)) => {
                     debug!(handler = %name, event = %event, reason = %reason, "hook block on read-only event (ignored)");
                 },
+                // Resolved inside handle_with_retry; never surfaces here.
+                Ok(HookAction::Retry { .. }) => {},
                 Err(e) => {
                     warn!(handler = %name, event = %event, error = %e, "hook handler failed");
                 },
@@ -532,7 +574,7 @@ impl HookRegistry {
             }
 
             let start = Instant::now();
-            let result = entry.handler.handle(event, payload).await;
+            let result = Self::handle_with_retry(entry.handler.as_ref(), event, payload).await;
             let latency = start.elapsed();
 
             match result {
@@ -557,6 +599,10 @@ impl HookRegistry {
                         return Ok(HookAction::Block(reason));
                     }
                 },
+                // Resolved inside handle_with_retry; never surfaces here.
+                Ok(HookAction::Retry { .. }) => {
+                    entry.stats.record_success(latency);
+                },
                 Err(e) => {
                     entry.stats.record_failure(latency);
                     warn!(handler = entry.handler.name(), event = %event, error = %e, "hook handler failed");
@@ -613,6 +659,10 @@ impl HookRegistry {
                         return Ok(HookAction::Block(reason));
                     }
                 },
+                // No sleeping on the sync hot path — treat as Continue.
+                Ok(HookAction::Retry { .. }) => {
+                    entry.stats.record_success(latency);
+                },
                 Err(e) => {
                     entry.stats.record_failure(latency);
                     warn!(handler = entry.handler.name(), event = %event, error = %e, "hook handler failed (sync)");
@@ -646,6 +646,21 @@ pub struct ServerConfig {
     ///
     /// When unset, Moltis falls back to the package repository metadata.
     pub update_repository_url: Option<String>,
+    /// Policy for the `/ready` aggregate readiness probe.
+    pub readiness: ReadinessConfig,
+}
+
+/// Policy for what the `/ready` endpoint counts as "ready".
+///
+/// The store must always be reachable; these flags tighten the policy for
+/// deployments where channels or MCP servers are load-bearing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReadinessConfig {
+    /// Report unready unless at least one channel account is connected.
+    pub require_channel: bool,
+    /// Report unready unless every enabled MCP server is running.
+    pub require_all_mcp: bool,
 }
 
 impl Default for ServerConfig {
@@ -656,6 +671,7 @@ impl Default for ServerConfig {
             http_request_logs: false,
             ws_request_logs: false,
             update_repository_url: None,
+            readiness: ReadinessConfig::default(),
         }
     }
 }
@@ -290,6 +290,13 @@ fn build_schema_map() -> KnownKeys {
                 ("http_request_logs", Leaf),
                 ("ws_request_logs", Leaf),
                 ("update_repository_url", Leaf),
+                (
+                    "readiness",
+                    Struct(HashMap::from([
+                        ("require_channel", Leaf),
+                        ("require_all_mcp", Leaf),
+                    ])),
+                ),
             ])),
         ),
         ("providers", MapWithFields {
@@ -0,0 +1,17 @@
+-- Dead-letter queue for failed outbound channel sends
+-- Owned by: moltis-gateway crate
+
+CREATE TABLE IF NOT EXISTS dead_letters (
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id   TEXT    NOT NULL,
+    channel_type TEXT    NOT NULL,
+    recipient    TEXT    NOT NULL,
+    body         TEXT    NOT NULL,
+    reply_to     TEXT,
+    error        TEXT    NOT NULL,
+    attempts     INTEGER NOT NULL DEFAULT 0,
+    created_at   INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_dead_letters_account_created
+    ON dead_letters (account_id, created_at DESC);
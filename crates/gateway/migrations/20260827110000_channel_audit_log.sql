@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS channel_audit_log (
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id   TEXT    NOT NULL,
+    channel_type TEXT    NOT NULL,
+    peer_id      TEXT    NOT NULL,
+    chat_type    TEXT    NOT NULL,
+    allowed      INTEGER NOT NULL DEFAULT 0,
+    reason       TEXT    NOT NULL,
+    created_at   INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_channel_audit_log_account_created
+    ON channel_audit_log (account_id, created_at DESC);
@@ -0,0 +1,169 @@
+use {
+    async_trait::async_trait,
+    moltis_channels::audit::{AuditLog, AuditRecord},
+    sqlx::SqlitePool,
+};
+
+/// SQLite-backed audit log of channel gating decisions.
+pub struct SqliteAuditLog {
+    pool: SqlitePool,
+}
+
+impl SqliteAuditLog {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Initialize the channel_audit_log table schema.
+    ///
+    /// **Deprecated**: Schema is now managed by sqlx migrations.
+    /// This method is retained for tests that use in-memory databases.
+    #[doc(hidden)]
+    pub async fn init(pool: &SqlitePool) -> anyhow::Result<()> {
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS channel_audit_log (
+                id           INTEGER PRIMARY KEY AUTOINCREMENT,
+                account_id   TEXT    NOT NULL,
+                channel_type TEXT    NOT NULL,
+                peer_id      TEXT    NOT NULL,
+                chat_type    TEXT    NOT NULL,
+                allowed      INTEGER NOT NULL DEFAULT 0,
+                reason       TEXT    NOT NULL,
+                created_at   INTEGER NOT NULL
+            )",
+        )
+        .execute(pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_channel_audit_log_account_created
+             ON channel_audit_log (account_id, created_at DESC)",
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+type AuditRow = (i64, String, String, String, String, bool, String, i64);
+
+fn row_to_record(r: AuditRow) -> AuditRecord {
+    AuditRecord {
+        id: r.0,
+        account_id: r.1,
+        channel_type: r.2,
+        peer_id: r.3,
+        chat_type: r.4,
+        allowed: r.5,
+        reason: r.6,
+        created_at: r.7,
+    }
+}
+
+#[async_trait]
+impl AuditLog for SqliteAuditLog {
+    async fn record(&self, record: AuditRecord) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO channel_audit_log
+             (account_id, channel_type, peer_id, chat_type, allowed, reason, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&record.account_id)
+        .bind(&record.channel_type)
+        .bind(&record.peer_id)
+        .bind(&record.chat_type)
+        .bind(record.allowed)
+        .bind(&record.reason)
+        .bind(record.created_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn list_by_account(
+        &self,
+        account_id: &str,
+        limit: u32,
+    ) -> anyhow::Result<Vec<AuditRecord>> {
+        let rows = sqlx::query_as::<_, AuditRow>(
+            "SELECT id, account_id, channel_type, peer_id, chat_type, allowed, reason, created_at
+             FROM channel_audit_log
+             WHERE account_id = ?
+             ORDER BY created_at DESC
+             LIMIT ?",
+        )
+        .bind(account_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(row_to_record).collect())
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_store() -> SqliteAuditLog {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        SqliteAuditLog::init(&pool).await.unwrap();
+        SqliteAuditLog::new(pool)
+    }
+
+    fn sample(account_id: &str, peer_id: &str, allowed: bool, reason: &str) -> AuditRecord {
+        AuditRecord {
+            id: 0,
+            account_id: account_id.into(),
+            channel_type: "telegram".into(),
+            peer_id: peer_id.into(),
+            chat_type: "dm".into(),
+            allowed,
+            reason: reason.into(),
+            created_at: 1700000000,
+        }
+    }
+
+    #[tokio::test]
+    async fn records_allowed_and_denied_decisions() {
+        let store = test_store().await;
+        store
+            .record(sample("bot1", "alice", true, "allowlisted"))
+            .await
+            .unwrap();
+        store
+            .record(sample("bot1", "mallory", false, "not_on_allowlist"))
+            .await
+            .unwrap();
+
+        let records = store.list_by_account("bot1", 10).await.unwrap();
+        assert_eq!(records.len(), 2);
+        let alice = records.iter().find(|r| r.peer_id == "alice").unwrap();
+        assert!(alice.allowed);
+        assert_eq!(alice.reason, "allowlisted");
+        let mallory = records.iter().find(|r| r.peer_id == "mallory").unwrap();
+        assert!(!mallory.allowed);
+        assert_eq!(mallory.reason, "not_on_allowlist");
+    }
+
+    #[tokio::test]
+    async fn list_scopes_by_account_and_limit() {
+        let store = test_store().await;
+        for i in 0..5 {
+            let mut r = sample("bot1", "alice", true, "open");
+            r.created_at += i;
+            store.record(r).await.unwrap();
+        }
+        store
+            .record(sample("bot2", "bob", true, "open"))
+            .await
+            .unwrap();
+
+        let records = store.list_by_account("bot1", 3).await.unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(records[0].created_at > records[1].created_at);
+        assert!(store.list_by_account("bot2", 10).await.unwrap().len() == 1);
+    }
+}
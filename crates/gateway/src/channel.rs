@@ -11,8 +11,10 @@ use {moltis_channels::ChannelPlugin, moltis_telegram::TelegramPlugin};
 
 use {
     moltis_channels::{
+        dead_letter::{DeadLetter, DeadLetterQueue, retry_dead_letter},
         message_log::MessageLog,
         store::{ChannelStore, StoredChannel},
+        tool_trace::ToolTraceConfig,
     },
     moltis_sessions::metadata::SqliteSessionMetadata,
 };
@@ -26,12 +28,66 @@ fn unix_now() -> i64 {
         .as_secs() as i64
 }
 
+/// Dispatch a direct operator send through a channel outbound.
+///
+/// Parses `to`, `text`, and optional `reply_to` from the params; the
+/// account is resolved by the caller. Failed sends are parked in the
+/// dead-letter queue (when one is configured) so they can be retried via
+/// `channels.dead_letters.retry` instead of vanishing with a log line.
+async fn dispatch_send(
+    outbound: &dyn moltis_channels::ChannelOutbound,
+    dead_letters: Option<&dyn DeadLetterQueue>,
+    account_id: &str,
+    params: &Value,
+) -> ServiceResult {
+    let to = params
+        .get("to")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "missing 'to'".to_string())?;
+
+    let text = params
+        .get("text")
+        .and_then(|v| v.as_str())
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| "missing 'text'".to_string())?;
+
+    let reply_to = params.get("reply_to").and_then(|v| v.as_str());
+
+    info!(account_id, to, "direct channel send");
+    if let Err(e) = outbound.send_text(account_id, to, text, reply_to).await {
+        error!(error = %e, account_id, to, "direct channel send failed");
+        if let Some(queue) = dead_letters {
+            let letter = DeadLetter {
+                id: 0,
+                account_id: account_id.to_string(),
+                channel_type: "telegram".into(),
+                recipient: to.to_string(),
+                body: text.to_string(),
+                reply_to: reply_to.map(str::to_string),
+                error: e.to_string(),
+                attempts: 1,
+                created_at: unix_now(),
+            };
+            match queue.push(letter).await {
+                Ok(id) => info!(dead_letter_id = id, account_id, to, "send parked for retry"),
+                Err(push_err) => {
+                    error!(error = %push_err, account_id, to, "failed to park dead letter");
+                },
+            }
+        }
+        return Err(e.to_string());
+    }
+
+    Ok(serde_json::json!({ "sent": true }))
+}
+
 /// Live channel service backed by `TelegramPlugin`.
 pub struct LiveChannelService {
     telegram: Arc<RwLock<TelegramPlugin>>,
     store: Arc<dyn ChannelStore>,
     message_log: Arc<dyn MessageLog>,
     session_metadata: Arc<SqliteSessionMetadata>,
+    dead_letters: Option<Arc<dyn DeadLetterQueue>>,
 }
 
 impl LiveChannelService {
@@ -46,8 +102,17 @@ impl LiveChannelService {
             store,
             message_log,
             session_metadata,
+            dead_letters: None,
         }
     }
+
+    /// Park failed direct sends in `queue` and serve the
+    /// `channels.dead_letters.*` methods from it.
+    #[must_use]
+    pub fn with_dead_letters(mut self, queue: Arc<dyn DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(queue);
+        self
+    }
 }
 
 #[async_trait]
@@ -234,8 +299,27 @@ impl ChannelService for LiveChannelService {
         Ok(serde_json::json!({ "updated": account_id }))
     }
 
-    async fn send(&self, _params: Value) -> ServiceResult {
-        Err("direct channel send not yet implemented".into())
+    async fn send(&self, params: Value) -> ServiceResult {
+        let account_id = params
+            .get("account_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| "missing 'account_id'".to_string())?;
+
+        let outbound = {
+            let tg = self.telegram.read().await;
+            if !tg.account_ids().iter().any(|a| a == account_id) {
+                return Err(format!("channel account '{account_id}' is not running"));
+            }
+            tg.shared_outbound()
+        };
+
+        dispatch_send(
+            outbound.as_ref(),
+            self.dead_letters.as_deref(),
+            account_id,
+            &params,
+        )
+        .await
     }
 
     async fn senders_list(&self, params: Value) -> ServiceResult {
@@ -419,4 +503,253 @@ impl ChannelService for LiveChannelService {
         info!(account_id, identifier, "sender denied");
         Ok(serde_json::json!({ "denied": identifier }))
     }
+
+    async fn dead_letters_list(&self, params: Value) -> ServiceResult {
+        let queue = self
+            .dead_letters
+            .as_ref()
+            .ok_or_else(|| "dead-letter queue not configured".to_string())?;
+
+        let account_id = params.get("account_id").and_then(|v| v.as_str());
+        let limit = params
+            .get("limit")
+            .and_then(Value::as_u64)
+            .map_or(50, |l| l.min(500) as u32);
+
+        let letters = queue
+            .list(account_id, limit)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(serde_json::json!({ "dead_letters": letters }))
+    }
+
+    async fn dead_letters_retry(&self, params: Value) -> ServiceResult {
+        let queue = self
+            .dead_letters
+            .as_ref()
+            .ok_or_else(|| "dead-letter queue not configured".to_string())?;
+
+        let id = params
+            .get("id")
+            .and_then(Value::as_i64)
+            .ok_or_else(|| "missing 'id'".to_string())?;
+
+        let outbound = {
+            let tg = self.telegram.read().await;
+            tg.shared_outbound()
+        };
+
+        retry_dead_letter(queue.as_ref(), outbound.as_ref(), id)
+            .await
+            .map_err(|e| {
+                error!(error = %e, dead_letter_id = id, "dead-letter retry failed");
+                e.to_string()
+            })?;
+
+        info!(dead_letter_id = id, "dead letter redelivered");
+        Ok(serde_json::json!({ "retried": id }))
+    }
+
+    async fn tool_trace_config(&self, account_id: &str) -> ToolTraceConfig {
+        let tg = self.telegram.read().await;
+        tg.account_config(account_id)
+            .and_then(|cfg| cfg.get("tool_trace").cloned())
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        moltis_channels::ChannelOutbound,
+        moltis_common::types::ReplyPayload,
+        std::sync::Mutex,
+    };
+
+    #[derive(Default)]
+    struct RecordingOutbound {
+        calls: Mutex<Vec<(String, String, String, Option<String>)>>,
+    }
+
+    #[async_trait]
+    impl ChannelOutbound for RecordingOutbound {
+        async fn send_text(
+            &self,
+            account_id: &str,
+            to: &str,
+            text: &str,
+            reply_to: Option<&str>,
+        ) -> anyhow::Result<()> {
+            self.calls.lock().unwrap_or_else(|e| e.into_inner()).push((
+                account_id.to_string(),
+                to.to_string(),
+                text.to_string(),
+                reply_to.map(str::to_string),
+            ));
+            Ok(())
+        }
+
+        async fn send_media(
+            &self,
+            _account_id: &str,
+            _to: &str,
+            _payload: &ReplyPayload,
+            _reply_to: Option<&str>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_send_forwards_to_outbound() {
+        let outbound = RecordingOutbound::default();
+        let params = serde_json::json!({
+            "account_id": "bot1",
+            "to": "12345",
+            "text": "hello",
+            "reply_to": "99",
+        });
+
+        let result = dispatch_send(&outbound, None, "bot1", &params)
+            .await
+            .unwrap();
+        assert_eq!(result, serde_json::json!({ "sent": true }));
+
+        let calls = outbound.calls.lock().unwrap();
+        assert_eq!(calls.as_slice(), [(
+            "bot1".to_string(),
+            "12345".to_string(),
+            "hello".to_string(),
+            Some("99".to_string())
+        )]);
+    }
+
+    #[tokio::test]
+    async fn dispatch_send_requires_to_and_text() {
+        let outbound = RecordingOutbound::default();
+        let missing_to = serde_json::json!({ "text": "hi" });
+        assert!(
+            dispatch_send(&outbound, None, "bot1", &missing_to)
+                .await
+                .is_err()
+        );
+
+        let empty_text = serde_json::json!({ "to": "1", "text": "" });
+        assert!(
+            dispatch_send(&outbound, None, "bot1", &empty_text)
+                .await
+                .is_err()
+        );
+        assert!(outbound.calls.lock().unwrap().is_empty());
+    }
+
+    /// Outbound that always fails, for dead-letter coverage.
+    struct FailingOutbound;
+
+    #[async_trait]
+    impl ChannelOutbound for FailingOutbound {
+        async fn send_text(
+            &self,
+            _account_id: &str,
+            _to: &str,
+            _text: &str,
+            _reply_to: Option<&str>,
+        ) -> anyhow::Result<()> {
+            anyhow::bail!("network down")
+        }
+
+        async fn send_media(
+            &self,
+            _account_id: &str,
+            _to: &str,
+            _payload: &ReplyPayload,
+            _reply_to: Option<&str>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// In-memory dead-letter queue for dispatch tests.
+    #[derive(Default)]
+    struct MemoryQueue {
+        letters: Mutex<Vec<DeadLetter>>,
+    }
+
+    #[async_trait]
+    impl DeadLetterQueue for MemoryQueue {
+        async fn push(&self, mut letter: DeadLetter) -> anyhow::Result<i64> {
+            let mut letters = self.letters.lock().unwrap_or_else(|e| e.into_inner());
+            letter.id = letters.len() as i64 + 1;
+            let id = letter.id;
+            letters.push(letter);
+            Ok(id)
+        }
+
+        async fn list(
+            &self,
+            _account_id: Option<&str>,
+            _limit: u32,
+        ) -> anyhow::Result<Vec<DeadLetter>> {
+            Ok(self
+                .letters
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone())
+        }
+
+        async fn get(&self, id: i64) -> anyhow::Result<Option<DeadLetter>> {
+            Ok(self
+                .letters
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .find(|l| l.id == id)
+                .cloned())
+        }
+
+        async fn remove(&self, id: i64) -> anyhow::Result<()> {
+            self.letters
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .retain(|l| l.id != id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_send_is_parked_in_dead_letter_queue() {
+        let queue = MemoryQueue::default();
+        let params = serde_json::json!({
+            "to": "12345",
+            "text": "hello",
+            "reply_to": "99",
+        });
+
+        let result = dispatch_send(&FailingOutbound, Some(&queue), "bot1", &params).await;
+        assert!(result.is_err());
+
+        let letters = queue.letters.lock().unwrap();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].account_id, "bot1");
+        assert_eq!(letters[0].recipient, "12345");
+        assert_eq!(letters[0].body, "hello");
+        assert_eq!(letters[0].reply_to.as_deref(), Some("99"));
+        assert!(letters[0].error.contains("network down"));
+    }
+
+    #[tokio::test]
+    async fn invalid_params_are_not_parked() {
+        let queue = MemoryQueue::default();
+        let missing_to = serde_json::json!({ "text": "hi" });
+        assert!(
+            dispatch_send(&FailingOutbound, Some(&queue), "bot1", &missing_to)
+                .await
+                .is_err()
+        );
+        assert!(queue.letters.lock().unwrap().is_empty());
+    }
 }
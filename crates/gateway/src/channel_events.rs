@@ -11,7 +11,7 @@ use {
     moltis_channels::{
         ChannelAttachment, ChannelEvent, ChannelEventSink, ChannelMessageMeta, ChannelReplyTarget,
     },
-    moltis_sessions::metadata::SqliteSessionMetadata,
+    moltis_sessions::{ChannelSessionKey, metadata::SqliteSessionMetadata},
 };
 
 use crate::{
@@ -19,12 +19,16 @@ use crate::{
     state::GatewayState,
 };
 
-/// Default (deterministic) session key for a channel chat.
+/// Default (deterministic) session key for a channel chat, rendered from
+/// the canonical [`ChannelSessionKey`] grammar (`channel:account:scope`
+/// with the chat ID as the scope).
 fn default_channel_session_key(target: &ChannelReplyTarget) -> String {
-    format!(
-        "{}:{}:{}",
-        target.channel_type, target.account_id, target.chat_id
+    ChannelSessionKey::new(
+        target.channel_type.as_str(),
+        &target.account_id,
+        &target.chat_id,
     )
+    .to_string()
 }
 
 /// Resolve the active session key for a channel chat.
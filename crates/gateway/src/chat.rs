@@ -36,8 +36,8 @@ use {
         tool_registry::ToolRegistry,
     },
     moltis_sessions::{
-        ContentBlock, MessageContent, PersistedMessage, metadata::SqliteSessionMetadata,
-        store::SessionStore,
+        ChannelSessionKey, ContentBlock, MessageContent, PersistedMessage,
+        metadata::SqliteSessionMetadata, store::SessionStore,
     },
     moltis_skills::discover::SkillDiscoverer,
     moltis_tools::policy::{ToolPolicy, profile_tools},
@@ -6209,7 +6209,7 @@ async fn deliver_channel_replies(
             !streamed_target_keys.contains(&key)
         });
     }
-    let is_telegram_session = session_key.starts_with("telegram:");
+    let is_telegram_session = ChannelSessionKey::matches_channel(session_key, "telegram");
     if targets.is_empty() {
         let _ = state.drain_channel_status_log(session_key).await;
         if is_telegram_session {
@@ -6699,6 +6699,9 @@ async fn build_tts_payload(
 /// Buffer a tool execution status into the channel status log for a session.
 /// The buffered entries are appended as a collapsible logbook when the final
 /// response is delivered, instead of being sent as separate messages.
+///
+/// Accounts that opted in via `tool_trace` additionally get a live trace
+/// line ("🔧 ran exec: ls") sent silently while the turn runs.
 async fn send_tool_status_to_channels(
     state: &Arc<GatewayState>,
     session_key: &str,
@@ -6713,6 +6716,44 @@ async fn send_tool_status_to_channels(
     // Buffer the status message for the logbook
     let message = format_tool_status_message(tool_name, arguments);
     state.push_channel_status_log(session_key, message).await;
+
+    let Some(outbound) = state.services.channel_outbound_arc() else {
+        return;
+    };
+    let args = tool_trace_args(arguments);
+    for target in targets {
+        let trace = state
+            .services
+            .channel
+            .tool_trace_config(&target.account_id)
+            .await;
+        let Some(line) = trace.render(tool_name, &args) else {
+            continue;
+        };
+        if let Err(e) = outbound
+            .send_text_silent(&target.account_id, &target.chat_id, &line, None)
+            .await
+        {
+            warn!(
+                account_id = %target.account_id,
+                chat_id = %target.chat_id,
+                "failed to send tool trace line: {e}"
+            );
+        }
+    }
+}
+
+/// Render tool arguments for a trace line: single-field payloads
+/// (`{"command": "ls"}`) show the bare value, anything else compact JSON.
+fn tool_trace_args(arguments: &Value) -> String {
+    match arguments.as_object() {
+        Some(map) if map.len() == 1 => match map.values().next() {
+            Some(Value::String(s)) => s.clone(),
+            Some(v) => v.to_string(),
+            None => String::new(),
+        },
+        _ => arguments.to_string(),
+    }
 }
 
 /// Format a human-readable tool execution message.
@@ -9080,6 +9121,20 @@ mod tests {
         }
     }
 
+    // ── Tool trace argument rendering ────────────────────────────────────
+
+    #[test]
+    fn tool_trace_args_unwraps_single_string_field() {
+        let args = serde_json::json!({ "command": "ls -la" });
+        assert_eq!(tool_trace_args(&args), "ls -la");
+    }
+
+    #[test]
+    fn tool_trace_args_keeps_multi_field_payloads_as_json() {
+        let args = serde_json::json!({ "action": "navigate", "url": "https://example.com" });
+        assert_eq!(tool_trace_args(&args), args.to_string());
+    }
+
     // ── Logbook formatting tests ─────────────────────────────────────────
 
     #[test]
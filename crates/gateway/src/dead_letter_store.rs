@@ -0,0 +1,284 @@
+use {
+    async_trait::async_trait,
+    moltis_channels::dead_letter::{DeadLetter, DeadLetterQueue},
+    sqlx::SqlitePool,
+};
+
+/// SQLite-backed dead-letter queue for failed outbound sends.
+pub struct SqliteDeadLetterQueue {
+    pool: SqlitePool,
+}
+
+impl SqliteDeadLetterQueue {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Initialize the dead_letters table schema.
+    ///
+    /// **Deprecated**: Schema is now managed by sqlx migrations.
+    /// This method is retained for tests that use in-memory databases.
+    #[doc(hidden)]
+    pub async fn init(pool: &SqlitePool) -> anyhow::Result<()> {
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS dead_letters (
+                id           INTEGER PRIMARY KEY AUTOINCREMENT,
+                account_id   TEXT    NOT NULL,
+                channel_type TEXT    NOT NULL,
+                recipient    TEXT    NOT NULL,
+                body         TEXT    NOT NULL,
+                reply_to     TEXT,
+                error        TEXT    NOT NULL,
+                attempts     INTEGER NOT NULL DEFAULT 0,
+                created_at   INTEGER NOT NULL
+            )",
+        )
+        .execute(pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_dead_letters_account_created
+             ON dead_letters (account_id, created_at DESC)",
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+type DeadLetterRow = (
+    i64,
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    String,
+    u32,
+    i64,
+);
+
+fn row_to_letter(r: DeadLetterRow) -> DeadLetter {
+    DeadLetter {
+        id: r.0,
+        account_id: r.1,
+        channel_type: r.2,
+        recipient: r.3,
+        body: r.4,
+        reply_to: r.5,
+        error: r.6,
+        attempts: r.7,
+        created_at: r.8,
+    }
+}
+
+#[async_trait]
+impl DeadLetterQueue for SqliteDeadLetterQueue {
+    async fn push(&self, letter: DeadLetter) -> anyhow::Result<i64> {
+        let result = sqlx::query(
+            "INSERT INTO dead_letters
+             (account_id, channel_type, recipient, body, reply_to, error, attempts, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&letter.account_id)
+        .bind(&letter.channel_type)
+        .bind(&letter.recipient)
+        .bind(&letter.body)
+        .bind(&letter.reply_to)
+        .bind(&letter.error)
+        .bind(letter.attempts)
+        .bind(letter.created_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn list(&self, account_id: Option<&str>, limit: u32) -> anyhow::Result<Vec<DeadLetter>> {
+        let rows = match account_id {
+            Some(aid) => {
+                sqlx::query_as::<_, DeadLetterRow>(
+                    "SELECT id, account_id, channel_type, recipient, body, reply_to,
+                            error, attempts, created_at
+                     FROM dead_letters
+                     WHERE account_id = ?
+                     ORDER BY created_at DESC
+                     LIMIT ?",
+                )
+                .bind(aid)
+                .bind(limit)
+                .fetch_all(&self.pool)
+                .await?
+            },
+            None => {
+                sqlx::query_as::<_, DeadLetterRow>(
+                    "SELECT id, account_id, channel_type, recipient, body, reply_to,
+                            error, attempts, created_at
+                     FROM dead_letters
+                     ORDER BY created_at DESC
+                     LIMIT ?",
+                )
+                .bind(limit)
+                .fetch_all(&self.pool)
+                .await?
+            },
+        };
+
+        Ok(rows.into_iter().map(row_to_letter).collect())
+    }
+
+    async fn get(&self, id: i64) -> anyhow::Result<Option<DeadLetter>> {
+        let row = sqlx::query_as::<_, DeadLetterRow>(
+            "SELECT id, account_id, channel_type, recipient, body, reply_to,
+                    error, attempts, created_at
+             FROM dead_letters
+             WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(row_to_letter))
+    }
+
+    async fn remove(&self, id: i64) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM dead_letters WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        moltis_channels::{dead_letter::retry_dead_letter, plugin::ChannelOutbound},
+        moltis_common::types::ReplyPayload,
+        std::sync::{
+            Arc, Mutex,
+            atomic::{AtomicBool, Ordering},
+        },
+    };
+
+    async fn test_queue() -> SqliteDeadLetterQueue {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        SqliteDeadLetterQueue::init(&pool).await.unwrap();
+        SqliteDeadLetterQueue::new(pool)
+    }
+
+    fn sample_letter(account_id: &str, recipient: &str) -> DeadLetter {
+        DeadLetter {
+            id: 0,
+            account_id: account_id.into(),
+            channel_type: "telegram".into(),
+            recipient: recipient.into(),
+            body: "hello".into(),
+            reply_to: None,
+            error: "chat not found".into(),
+            attempts: 3,
+            created_at: 1700000000,
+        }
+    }
+
+    /// Outbound stub that records send_text calls and can be told to fail.
+    struct RecordingOutbound {
+        calls: Mutex<Vec<(String, String, String)>>,
+        fail: AtomicBool,
+    }
+
+    impl RecordingOutbound {
+        fn new(fail: bool) -> Self {
+            Self {
+                calls: Mutex::new(Vec::new()),
+                fail: AtomicBool::new(fail),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ChannelOutbound for RecordingOutbound {
+        async fn send_text(
+            &self,
+            account_id: &str,
+            to: &str,
+            text: &str,
+            _reply_to: Option<&str>,
+        ) -> anyhow::Result<()> {
+            self.calls.lock().unwrap().push((
+                account_id.to_string(),
+                to.to_string(),
+                text.to_string(),
+            ));
+            if self.fail.load(Ordering::SeqCst) {
+                anyhow::bail!("still down");
+            }
+            Ok(())
+        }
+
+        async fn send_media(
+            &self,
+            _account_id: &str,
+            _to: &str,
+            _payload: &ReplyPayload,
+            _reply_to: Option<&str>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn exhausted_send_lands_in_queue() {
+        let queue = test_queue().await;
+        let id = queue.push(sample_letter("bot1", "42")).await.unwrap();
+        assert!(id > 0);
+
+        let letters = queue.list(Some("bot1"), 10).await.unwrap();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].recipient, "42");
+        assert_eq!(letters[0].error, "chat not found");
+        assert_eq!(letters[0].attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn list_filters_by_account() {
+        let queue = test_queue().await;
+        queue.push(sample_letter("bot1", "1")).await.unwrap();
+        queue.push(sample_letter("bot2", "2")).await.unwrap();
+
+        assert_eq!(queue.list(Some("bot1"), 10).await.unwrap().len(), 1);
+        assert_eq!(queue.list(None, 10).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_success_redelivers_and_removes() {
+        let queue = test_queue().await;
+        let id = queue.push(sample_letter("bot1", "42")).await.unwrap();
+
+        let outbound = Arc::new(RecordingOutbound::new(false));
+        retry_dead_letter(&queue, outbound.as_ref(), id)
+            .await
+            .unwrap();
+
+        {
+            let calls = outbound.calls.lock().unwrap();
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0], ("bot1".into(), "42".into(), "hello".into()));
+        }
+
+        assert!(queue.get(id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn retry_failure_keeps_letter_parked() {
+        let queue = test_queue().await;
+        let id = queue.push(sample_letter("bot1", "42")).await.unwrap();
+
+        let outbound = Arc::new(RecordingOutbound::new(true));
+        let result = retry_dead_letter(&queue, outbound.as_ref(), id).await;
+        assert!(result.is_err());
+        assert!(queue.get(id).await.unwrap().is_some());
+    }
+}
@@ -11,6 +11,7 @@
 //! invoked through method handlers registered in `methods.rs`.
 
 pub mod approval;
+pub mod audit_store;
 pub mod auth;
 pub mod auth_middleware;
 pub mod auth_routes;
@@ -22,6 +23,7 @@ pub mod channel_store;
 pub mod chat;
 pub mod chat_error;
 pub mod cron;
+pub mod dead_letter_store;
 pub mod env_routes;
 #[cfg(feature = "local-llm")]
 pub mod local_llm_setup;
@@ -43,6 +45,7 @@ pub mod provider_setup;
 pub mod push;
 #[cfg(feature = "push-notifications")]
 pub mod push_routes;
+pub mod readiness;
 pub mod request_throttle;
 pub mod server;
 pub mod services;
@@ -71,10 +71,11 @@ pub async fn run_health_monitor(state: Arc<GatewayState>, mcp: Arc<LiveMcpServic
             }
 
             // Clear tracking when server is back up or in OAuth flow.
-            if !is_down || awaiting_auth {
-                if restart_states.remove(&s.name).is_some() && s.state == "running" {
-                    info!(server = %s.name, "MCP server recovered");
-                }
+            if (!is_down || awaiting_auth)
+                && restart_states.remove(&s.name).is_some()
+                && s.state == "running"
+            {
+                info!(server = %s.name, "MCP server recovered");
             }
 
             prev_states.insert(s.name.clone(), s.state.clone());
@@ -44,6 +44,7 @@ const READ_METHODS: &[&str] = &[
     "channels.status",
     "channels.list",
     "channels.senders.list",
+    "channels.dead_letters.list",
     "status",
     "usage.status",
     "usage.cost",
@@ -142,6 +143,7 @@ const WRITE_METHODS: &[&str] = &[
     "channels.update",
     "channels.senders.approve",
     "channels.senders.deny",
+    "channels.dead_letters.retry",
     "sessions.switch",
     "sessions.fork",
     "sessions.voice.generate",
@@ -1641,6 +1643,32 @@ impl MethodRegistry {
                 })
             }),
         );
+        self.register(
+            "channels.dead_letters.list",
+            Box::new(|ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .services
+                        .channel
+                        .dead_letters_list(ctx.params.clone())
+                        .await
+                        .map_err(|e| ErrorShape::new(error_codes::UNAVAILABLE, e))
+                })
+            }),
+        );
+        self.register(
+            "channels.dead_letters.retry",
+            Box::new(|ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .services
+                        .channel
+                        .dead_letters_retry(ctx.params.clone())
+                        .await
+                        .map_err(|e| ErrorShape::new(error_codes::UNAVAILABLE, e))
+                })
+            }),
+        );
         self.register(
             "send",
             Box::new(|ctx| {
@@ -5800,6 +5828,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dead_letters_list_requires_read() {
+        assert!(
+            authorize_method(
+                "channels.dead_letters.list",
+                "operator",
+                &scopes(&["operator.read"])
+            )
+            .is_none()
+        );
+        assert!(
+            authorize_method("channels.dead_letters.list", "operator", &scopes(&[])).is_some()
+        );
+    }
+
+    #[test]
+    fn dead_letters_retry_requires_write() {
+        assert!(
+            authorize_method(
+                "channels.dead_letters.retry",
+                "operator",
+                &scopes(&["operator.write"])
+            )
+            .is_none()
+        );
+        assert!(
+            authorize_method(
+                "channels.dead_letters.retry",
+                "operator",
+                &scopes(&["operator.read"])
+            )
+            .is_some()
+        );
+    }
+
     #[test]
     fn admin_scope_allows_all_sender_methods() {
         for method in &[
@@ -1053,6 +1053,7 @@ pub(crate) fn detect_auto_provider_sources_with_overrides(
     let config_dir = current_config_dir();
     let provider_keys_path = config_dir.join("provider_keys.json");
     let oauth_tokens_path = config_dir.join("oauth_tokens.json");
+    #[cfg(feature = "local-llm")]
     let local_llm_config_path = config_dir.join("local-llm.json");
     let codex_path = codex_cli_auth_path();
 
@@ -0,0 +1,282 @@
+//! Aggregate readiness probe for orchestration (k8s-style) health checks.
+//!
+//! `/health` reports liveness only; `/ready` combines channel connectivity,
+//! MCP server health, and store reachability into a single status with
+//! per-subsystem detail. The policy for what counts as "ready" is
+//! configurable via `server.readiness` in `moltis.toml`.
+
+use std::sync::Arc;
+
+use {moltis_config::schema::ReadinessConfig, serde::Serialize};
+
+use crate::state::GatewayState;
+
+/// Overall readiness verdict.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadinessStatus {
+    /// All subsystems healthy.
+    Ready,
+    /// Serving, but some non-required subsystem is impaired.
+    Degraded,
+    /// A required subsystem is down; traffic should not be routed here.
+    Unready,
+}
+
+impl ReadinessStatus {
+    /// Whether a load balancer should keep routing traffic here.
+    /// Degraded still serves; only unready returns 503.
+    #[must_use]
+    pub fn is_serving(self) -> bool {
+        self != Self::Unready
+    }
+}
+
+/// Raw subsystem states fed into the aggregation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubsystemSnapshot {
+    /// Channel accounts currently connected.
+    pub channels_connected: usize,
+    /// Channel accounts configured.
+    pub channels_total: usize,
+    /// Enabled MCP servers in the `running` state.
+    pub mcp_running: usize,
+    /// Enabled MCP servers.
+    pub mcp_total: usize,
+    /// Whether the session store answered a ping.
+    pub store_reachable: bool,
+}
+
+/// Up/total counts for one subsystem.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct SubsystemDetail {
+    pub up: usize,
+    pub total: usize,
+}
+
+/// Aggregated readiness with per-subsystem detail.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ReadinessReport {
+    pub status: ReadinessStatus,
+    pub channels: SubsystemDetail,
+    pub mcp: SubsystemDetail,
+    pub store: StoreDetail,
+}
+
+/// Store reachability detail.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct StoreDetail {
+    pub reachable: bool,
+}
+
+/// Aggregate subsystem states into a readiness verdict under `policy`.
+///
+/// An unreachable store is always unready. Subsystems the policy marks as
+/// required make the gateway unready when they fail; otherwise partial
+/// failures (some accounts disconnected, some MCP servers down) only
+/// degrade. Deployments with no channels or MCP servers configured are
+/// ready unless the policy requires them.
+#[must_use]
+pub fn evaluate(policy: &ReadinessConfig, snap: &SubsystemSnapshot) -> ReadinessReport {
+    let status = if !snap.store_reachable
+        || (policy.require_channel && snap.channels_connected == 0)
+        || (policy.require_all_mcp && snap.mcp_running < snap.mcp_total)
+    {
+        ReadinessStatus::Unready
+    } else if snap.channels_connected < snap.channels_total || snap.mcp_running < snap.mcp_total {
+        ReadinessStatus::Degraded
+    } else {
+        ReadinessStatus::Ready
+    };
+
+    ReadinessReport {
+        status,
+        channels: SubsystemDetail {
+            up: snap.channels_connected,
+            total: snap.channels_total,
+        },
+        mcp: SubsystemDetail {
+            up: snap.mcp_running,
+            total: snap.mcp_total,
+        },
+        store: StoreDetail {
+            reachable: snap.store_reachable,
+        },
+    }
+}
+
+/// Probe all subsystems and aggregate under the configured policy.
+pub async fn readiness(state: &Arc<GatewayState>) -> ReadinessReport {
+    let policy = state.inner.read().await.readiness_config.clone();
+
+    let (channel_status, mcp_list, store_reachable) = tokio::join!(
+        state.services.channel.status(),
+        state.services.mcp.list(),
+        ping_store(state),
+    );
+
+    let (channels_connected, channels_total) = channel_counts(channel_status.ok().as_ref());
+    let (mcp_running, mcp_total) = mcp_counts(mcp_list.ok().as_ref());
+
+    evaluate(&policy, &SubsystemSnapshot {
+        channels_connected,
+        channels_total,
+        mcp_running,
+        mcp_total,
+        store_reachable,
+    })
+}
+
+/// Ping the session metadata store. Deployments without a store (tests,
+/// noop services) have nothing to check and count as reachable.
+async fn ping_store(state: &Arc<GatewayState>) -> bool {
+    match &state.services.session_metadata {
+        Some(meta) => meta.ping().await,
+        None => true,
+    }
+}
+
+/// Count (connected, total) channel accounts from `channels.status` output.
+fn channel_counts(status: Option<&serde_json::Value>) -> (usize, usize) {
+    let Some(channels) = status
+        .and_then(|v| v.get("channels"))
+        .and_then(|v| v.as_array())
+    else {
+        return (0, 0);
+    };
+    let connected = channels
+        .iter()
+        .filter(|c| c.get("status").and_then(|s| s.as_str()) == Some("connected"))
+        .count();
+    (connected, channels.len())
+}
+
+/// Count (running, total) enabled MCP servers from `mcp.list` output.
+fn mcp_counts(list: Option<&serde_json::Value>) -> (usize, usize) {
+    let Some(servers) = list.and_then(|v| v.as_array()) else {
+        return (0, 0);
+    };
+    let enabled: Vec<_> = servers
+        .iter()
+        .filter(|s| s.get("enabled").and_then(|e| e.as_bool()).unwrap_or(false))
+        .collect();
+    let running = enabled
+        .iter()
+        .filter(|s| s.get("state").and_then(|v| v.as_str()) == Some("running"))
+        .count();
+    (running, enabled.len())
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> SubsystemSnapshot {
+        SubsystemSnapshot {
+            channels_connected: 1,
+            channels_total: 1,
+            mcp_running: 2,
+            mcp_total: 2,
+            store_reachable: true,
+        }
+    }
+
+    #[test]
+    fn all_subsystems_up_is_ready() {
+        let report = evaluate(&ReadinessConfig::default(), &snapshot());
+        assert_eq!(report.status, ReadinessStatus::Ready);
+        assert!(report.status.is_serving());
+    }
+
+    #[test]
+    fn nothing_configured_is_ready() {
+        let snap = SubsystemSnapshot {
+            store_reachable: true,
+            ..SubsystemSnapshot::default()
+        };
+        let report = evaluate(&ReadinessConfig::default(), &snap);
+        assert_eq!(report.status, ReadinessStatus::Ready);
+    }
+
+    #[test]
+    fn disconnected_channel_degrades() {
+        let snap = SubsystemSnapshot {
+            channels_connected: 0,
+            ..snapshot()
+        };
+        let report = evaluate(&ReadinessConfig::default(), &snap);
+        assert_eq!(report.status, ReadinessStatus::Degraded);
+        assert!(report.status.is_serving());
+    }
+
+    #[test]
+    fn down_mcp_server_degrades() {
+        let snap = SubsystemSnapshot {
+            mcp_running: 1,
+            ..snapshot()
+        };
+        let report = evaluate(&ReadinessConfig::default(), &snap);
+        assert_eq!(report.status, ReadinessStatus::Degraded);
+    }
+
+    #[test]
+    fn unreachable_store_is_unready() {
+        let snap = SubsystemSnapshot {
+            store_reachable: false,
+            ..snapshot()
+        };
+        let report = evaluate(&ReadinessConfig::default(), &snap);
+        assert_eq!(report.status, ReadinessStatus::Unready);
+        assert!(!report.status.is_serving());
+    }
+
+    #[test]
+    fn required_channel_missing_is_unready() {
+        let policy = ReadinessConfig {
+            require_channel: true,
+            ..ReadinessConfig::default()
+        };
+        let snap = SubsystemSnapshot {
+            channels_connected: 0,
+            ..snapshot()
+        };
+        assert_eq!(evaluate(&policy, &snap).status, ReadinessStatus::Unready);
+    }
+
+    #[test]
+    fn required_mcp_down_is_unready() {
+        let policy = ReadinessConfig {
+            require_all_mcp: true,
+            ..ReadinessConfig::default()
+        };
+        let snap = SubsystemSnapshot {
+            mcp_running: 0,
+            ..snapshot()
+        };
+        assert_eq!(evaluate(&policy, &snap).status, ReadinessStatus::Unready);
+    }
+
+    #[test]
+    fn channel_counts_parse_status_payload() {
+        let status = serde_json::json!({
+            "channels": [
+                {"status": "connected"},
+                {"status": "disconnected"},
+                {"status": "error"},
+            ]
+        });
+        assert_eq!(channel_counts(Some(&status)), (1, 3));
+        assert_eq!(channel_counts(None), (0, 0));
+    }
+
+    #[test]
+    fn mcp_counts_skip_disabled_servers() {
+        let list = serde_json::json!([
+            {"enabled": true, "state": "running"},
+            {"enabled": true, "state": "dead"},
+            {"enabled": false, "state": "stopped"},
+        ]);
+        assert_eq!(mcp_counts(Some(&list)), (1, 2));
+    }
+}
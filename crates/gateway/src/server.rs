@@ -847,6 +847,7 @@ pub fn build_gateway_app(
 
     let mut router = Router::new()
         .route("/health", get(health_handler))
+        .route("/ready", get(readiness_handler))
         .route("/ws/chat", get(ws_upgrade_handler));
 
     // Nest auth routes if credential store is available.
@@ -914,6 +915,7 @@ pub fn build_gateway_app(
 
     let mut router = Router::new()
         .route("/health", get(health_handler))
+        .route("/ready", get(readiness_handler))
         .route("/ws/chat", get(ws_upgrade_handler));
 
     // Add Prometheus metrics endpoint (unauthenticated for scraping).
@@ -2107,9 +2109,11 @@ pub async fn start_gateway(
         let channel_sink = Arc::new(crate::channel_events::GatewayChannelEventSink::new(
             Arc::clone(&deferred_state),
         ));
+        let audit_log = Arc::new(crate::audit_store::SqliteAuditLog::new(db_pool.clone()));
         let mut tg_plugin = moltis_telegram::TelegramPlugin::new()
             .with_message_log(Arc::clone(&message_log))
-            .with_event_sink(channel_sink);
+            .with_event_sink(channel_sink)
+            .with_audit_log(audit_log);
 
         // Start channels from config file (these take precedence).
         let tg_accounts = &config.channels.telegram;
@@ -2142,6 +2146,28 @@ pub async fn start_gateway(
                         channel_type = ch.channel_type,
                         "starting stored channel"
                     );
+                    let mut ch = ch;
+                    match moltis_channels::store::migrate_config(
+                        &mut ch.config,
+                        moltis_telegram::config::CONFIG_MIGRATIONS,
+                    ) {
+                        Ok(true) => {
+                            if let Err(e) = channel_store.upsert(ch.clone()).await {
+                                tracing::warn!(
+                                    account_id = ch.account_id,
+                                    "failed to persist migrated channel config: {e}"
+                                );
+                            }
+                        },
+                        Ok(false) => {},
+                        Err(e) => {
+                            tracing::warn!(
+                                account_id = ch.account_id,
+                                "skipping stored channel with unsupported config: {e}"
+                            );
+                            continue;
+                        },
+                    }
                     if let Err(e) = tg_plugin.start_account(&ch.account_id, ch.config).await {
                         tracing::warn!(
                             account_id = ch.account_id,
@@ -2167,12 +2193,18 @@ pub async fn start_gateway(
         services = services.with_channel_outbound(tg_outbound);
         services = services.with_channel_stream_outbound(tg_stream_outbound);
 
-        services.channel = Arc::new(crate::channel::LiveChannelService::new(
-            tg_plugin,
-            channel_store,
-            Arc::clone(&message_log),
-            Arc::clone(&session_metadata),
+        let dead_letters = Arc::new(crate::dead_letter_store::SqliteDeadLetterQueue::new(
+            db_pool.clone(),
         ));
+        services.channel = Arc::new(
+            crate::channel::LiveChannelService::new(
+                tg_plugin,
+                channel_store,
+                Arc::clone(&message_log),
+                Arc::clone(&session_metadata),
+            )
+            .with_dead_letters(dead_letters),
+        );
     }
 
     services = services.with_session_metadata(Arc::clone(&session_metadata));
@@ -2669,8 +2701,13 @@ pub async fn start_gateway(
     // expensive and noisy — non-chat models (image, audio, video) would
     // generate spurious warnings.
 
-    // Store heartbeat config on state for gon data and RPC methods.
-    state.inner.write().await.heartbeat_config = config.heartbeat.clone();
+    // Store heartbeat and readiness config on state for gon data, RPC
+    // methods, and the /ready probe.
+    {
+        let mut inner = state.inner.write().await;
+        inner.heartbeat_config = config.heartbeat.clone();
+        inner.readiness_config = config.server.readiness.clone();
+    }
 
     // Wire live chat service (needs state reference, so done after state creation).
     {
@@ -2762,6 +2799,16 @@ pub async fn start_gateway(
             ),
         ));
 
+        // Register sessions search tool for cross-session content lookup.
+        tool_registry.register(Box::new(
+            moltis_tools::sessions_search::SessionsSearchTool::new(Arc::clone(&session_store)),
+        ));
+
+        // Register sessions create tool for provisioning delegated sessions.
+        tool_registry.register(Box::new(
+            moltis_tools::sessions_create::SessionsCreateTool::new(Arc::clone(&session_metadata)),
+        ));
+
         // Register location tool for browser geolocation requests.
         let location_requester = Arc::new(GatewayLocationRequester {
             state: Arc::clone(&state),
@@ -3696,6 +3743,18 @@ async fn health_handler(State(state): State<AppState>) -> impl IntoResponse {
     }))
 }
 
+/// Aggregate readiness probe for orchestration. Degraded still returns 200
+/// (keep routing traffic); only unready returns 503.
+async fn readiness_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let report = crate::readiness::readiness(&state.gateway).await;
+    let code = if report.status.is_serving() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (code, Json(report))
+}
+
 #[cfg(feature = "web-ui")]
 fn host_terminal_windows_payload(
     windows: Vec<HostTerminalWindowInfo>,
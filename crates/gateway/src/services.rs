@@ -4,7 +4,7 @@
 
 use {
     async_trait::async_trait,
-    moltis_channels::{ChannelOutbound, ChannelStreamOutbound},
+    moltis_channels::{ChannelOutbound, ChannelStreamOutbound, tool_trace::ToolTraceConfig},
     serde_json::Value,
     std::{collections::HashSet, path::Path, sync::Arc},
     tracing::warn,
@@ -307,6 +307,14 @@ pub trait ChannelService: Send + Sync {
     async fn senders_list(&self, params: Value) -> ServiceResult;
     async fn sender_approve(&self, params: Value) -> ServiceResult;
     async fn sender_deny(&self, params: Value) -> ServiceResult;
+    async fn dead_letters_list(&self, params: Value) -> ServiceResult;
+    async fn dead_letters_retry(&self, params: Value) -> ServiceResult;
+
+    /// Tool-trace rendering config for a channel account. Defaults to
+    /// hidden so tool calls stay invisible unless the account opts in.
+    async fn tool_trace_config(&self, _account_id: &str) -> ToolTraceConfig {
+        ToolTraceConfig::default()
+    }
 }
 
 pub struct NoopChannelService;
@@ -348,6 +356,14 @@ impl ChannelService for NoopChannelService {
     async fn sender_deny(&self, _p: Value) -> ServiceResult {
         Err("no channel service configured".into())
     }
+
+    async fn dead_letters_list(&self, _p: Value) -> ServiceResult {
+        Err("no channel service configured".into())
+    }
+
+    async fn dead_letters_retry(&self, _p: Value) -> ServiceResult {
+        Err("no channel service configured".into())
+    }
 }
 
 // ── Config ──────────────────────────────────────────────────────────────────
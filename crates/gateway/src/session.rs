@@ -12,8 +12,8 @@ use {
     moltis_common::hooks::HookRegistry,
     moltis_projects::ProjectStore,
     moltis_sessions::{
-        message::PersistedMessage, metadata::SqliteSessionMetadata, state_store::SessionStateStore,
-        store::SessionStore,
+        ChannelSessionKey, message::PersistedMessage, metadata::SqliteSessionMetadata,
+        state_store::SessionStateStore, store::SessionStore,
     },
     moltis_tools::sandbox::SandboxRouter,
 };
@@ -1571,7 +1571,7 @@ impl SessionService for LiveSessionService {
             // Keep main, channel-bound (telegram etc.), and cron sessions.
             if entry.key == "main"
                 || entry.channel_binding.is_some()
-                || entry.key.starts_with("telegram:")
+                || ChannelSessionKey::matches_channel(&entry.key, "telegram")
                 || entry.key.starts_with("cron:")
             {
                 continue;
@@ -255,6 +255,8 @@ pub struct GatewayInner {
     pub active_projects: HashMap<String, String>,
     /// Heartbeat configuration (for gon data and RPC methods).
     pub heartbeat_config: moltis_config::schema::HeartbeatConfig,
+    /// Readiness policy for the `/ready` aggregate probe.
+    pub readiness_config: moltis_config::schema::ReadinessConfig,
     /// Pending channel reply targets: when a channel message triggers a chat
     /// send, we queue the reply target so the "final" response can be routed
     /// back to the originating channel.
@@ -305,6 +307,7 @@ impl GatewayInner {
             active_sessions: HashMap::new(),
             active_projects: HashMap::new(),
             heartbeat_config: moltis_config::schema::HeartbeatConfig::default(),
+            readiness_config: moltis_config::schema::ReadinessConfig::default(),
             channel_reply_queue: HashMap::new(),
             tts_session_overrides: HashMap::new(),
             tts_channel_overrides: HashMap::new(),
@@ -12,6 +12,19 @@ const MEMORY_DIR_PREFIX: &str = "memory/";
 /// - `memory.md`
 /// - `memory/<name>.md` (single segment only)
 pub fn validate_memory_path(data_dir: &Path, file: &str) -> anyhow::Result<PathBuf> {
+    validate_memory_path_with_depth(data_dir, file, 1)
+}
+
+/// Like [`validate_memory_path`], but allowing up to `max_depth` path
+/// segments under `memory/` (so depth 2 permits `memory/projects/foo.md`).
+///
+/// Every segment is validated: `..`, hidden names, whitespace, backslashes,
+/// and absolute paths are rejected at any level.
+pub fn validate_memory_path_with_depth(
+    data_dir: &Path,
+    file: &str,
+    max_depth: usize,
+) -> anyhow::Result<PathBuf> {
     let path = file.trim();
     if path.is_empty() {
         anyhow::bail!("memory path cannot be empty");
@@ -29,31 +42,45 @@ pub fn validate_memory_path(data_dir: &Path, file: &str) -> anyhow::Result<PathB
         return Ok(data_dir.join(path));
     }
 
+    let invalid = || {
+        anyhow::anyhow!(
+            "invalid memory path '{path}': allowed targets are MEMORY.md, memory.md, or memory/<name>.md (up to {max_depth} level(s) under memory/)"
+        )
+    };
+
     let Some(name) = path.strip_prefix(MEMORY_DIR_PREFIX) else {
-        anyhow::bail!(
-            "invalid memory path '{path}': allowed targets are MEMORY.md, memory.md, or memory/<name>.md"
-        );
+        return Err(invalid());
     };
 
-    if !is_valid_memory_file_name(name) {
-        anyhow::bail!(
-            "invalid memory path '{path}': allowed targets are MEMORY.md, memory.md, or memory/<name>.md"
-        );
+    let segments: Vec<&str> = name.split('/').collect();
+    if segments.len() > max_depth {
+        return Err(invalid());
+    }
+
+    let Some((file_name, dirs)) = segments.split_last() else {
+        return Err(invalid());
+    };
+    if !dirs.iter().all(|dir| is_valid_memory_dir_name(dir)) {
+        return Err(invalid());
+    }
+    if !is_valid_memory_file_name(file_name) {
+        return Err(invalid());
     }
 
     Ok(data_dir.join(MEMORY_DIR_PREFIX).join(name))
 }
 
+/// Directory segments follow the same rules as file names minus the `.md`
+/// extension: non-empty, no whitespace, not hidden (also covers `..`).
+fn is_valid_memory_dir_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && !name.chars().any(char::is_whitespace)
+}
+
 fn is_valid_memory_file_name(name: &str) -> bool {
     if name.is_empty() {
         return false;
     }
 
-    // Exactly one level under memory/.
-    if name.contains('/') {
-        return false;
-    }
-
     if !name.ends_with(".md") {
         return false;
     }
@@ -76,7 +103,7 @@ fn is_valid_memory_file_name(name: &str) -> bool {
 mod tests {
     use std::path::Path;
 
-    use super::validate_memory_path;
+    use super::{validate_memory_path, validate_memory_path_with_depth};
 
     #[test]
     fn allows_root_memory_files() {
@@ -106,6 +133,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn allows_nested_paths_up_to_configured_depth() {
+        let root = Path::new("/tmp/moltis");
+
+        assert_eq!(
+            validate_memory_path_with_depth(root, "memory/projects/foo.md", 2).unwrap(),
+            root.join("memory").join("projects").join("foo.md")
+        );
+        // Default depth stays at 1 for backward compatibility.
+        assert!(validate_memory_path(root, "memory/projects/foo.md").is_err());
+        // Three levels exceed a depth of 2.
+        assert!(validate_memory_path_with_depth(root, "memory/a/b/c.md", 2).is_err());
+    }
+
+    #[test]
+    fn rejects_traversal_and_hidden_segments_in_nested_paths() {
+        let root = Path::new("/tmp/moltis");
+        let invalid = [
+            "memory/../foo.md",
+            "memory/projects/../foo.md",
+            "memory/.hidden/foo.md",
+            "memory/pro jects/foo.md",
+            "memory//foo.md",
+            "memory/projects/.hidden.md",
+            "/memory/projects/foo.md",
+            "memory\\projects\\foo.md",
+        ];
+
+        for item in invalid {
+            assert!(
+                validate_memory_path_with_depth(root, item, 2).is_err(),
+                "expected invalid path: {item}"
+            );
+        }
+    }
+
     #[test]
     fn rejects_invalid_paths() {
         let root = Path::new("/tmp/moltis");
@@ -21,12 +21,19 @@ pub struct ShellHookConfig {
     pub timeout: u64,
     #[serde(default)]
     pub env: HashMap<String, String>,
+    /// Cap on captured stdout/stderr in bytes; output beyond it is dropped.
+    #[serde(default = "default_max_output_bytes")]
+    pub max_output_bytes: usize,
 }
 
 fn default_timeout() -> u64 {
     10
 }
 
+fn default_max_output_bytes() -> usize {
+    1024 * 1024
+}
+
 /// Top-level hooks configuration section.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HooksConfig {
@@ -102,6 +109,36 @@ mod tests {
         }
     }
 
+    /// Returns Retry until `succeed_after` calls, then Modify.
+    struct RetryingHandler {
+        calls: std::sync::atomic::AtomicU32,
+        succeed_after: u32,
+        subscribed: Vec<HookEvent>,
+    }
+
+    #[async_trait]
+    impl HookHandler for RetryingHandler {
+        fn name(&self) -> &str {
+            "retrier"
+        }
+
+        fn events(&self) -> &[HookEvent] {
+            &self.subscribed
+        }
+
+        async fn handle(&self, _event: HookEvent, _payload: &HookPayload) -> Result<HookAction> {
+            let call = self
+                .calls
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if call < self.succeed_after {
+                return Ok(HookAction::Retry {
+                    after: std::time::Duration::from_millis(1),
+                });
+            }
+            Ok(HookAction::ModifyPayload(serde_json::json!({"ok": true})))
+        }
+    }
+
     struct FailingHandler {
         subscribed: Vec<HookEvent>,
     }
@@ -216,6 +253,34 @@ mod tests {
         assert!(matches!(result, HookAction::Continue));
     }
 
+    #[tokio::test]
+    async fn retrying_handler_is_reinvoked() {
+        let mut registry = HookRegistry::new();
+        registry.register(Arc::new(RetryingHandler {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            succeed_after: 2,
+            subscribed: vec![HookEvent::BeforeToolCall],
+        }));
+        let result = registry.dispatch(&test_payload()).await.unwrap();
+        match result {
+            HookAction::ModifyPayload(v) => assert_eq!(v, serde_json::json!({"ok": true})),
+            _ => panic!("expected ModifyPayload after retries, got: {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn exhausted_retries_continue() {
+        let mut registry = HookRegistry::new();
+        // Never succeeds; the registry gives up after its retry budget.
+        registry.register(Arc::new(RetryingHandler {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            succeed_after: u32::MAX,
+            subscribed: vec![HookEvent::BeforeToolCall],
+        }));
+        let result = registry.dispatch(&test_payload()).await.unwrap();
+        assert!(matches!(result, HookAction::Continue));
+    }
+
     #[test]
     fn payload_event_matches() {
         let payload = test_payload();
@@ -15,7 +15,10 @@ use {
     async_trait::async_trait,
     serde::{Deserialize, Serialize},
     serde_json::Value,
-    tokio::{io::AsyncWriteExt, process::Command},
+    tokio::{
+        io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
+        process::Command,
+    },
     tracing::{debug, warn},
 };
 
@@ -27,8 +30,14 @@ struct ShellHookResponse {
     action: String,
     #[serde(default)]
     data: Option<Value>,
+    /// Delay before re-invocation for `{"action": "retry", "after_ms": N}`.
+    #[serde(default)]
+    after_ms: Option<u64>,
 }
 
+/// Default cap on captured stdout/stderr (1 MiB).
+const DEFAULT_MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+
 /// A hook handler that executes an external shell command.
 pub struct ShellHookHandler {
     hook_name: String,
@@ -37,6 +46,7 @@ pub struct ShellHookHandler {
     timeout: Duration,
     env: HashMap<String, String>,
     working_dir: Option<PathBuf>,
+    max_output_bytes: usize,
 }
 
 impl ShellHookHandler {
@@ -55,9 +65,17 @@ impl ShellHookHandler {
             timeout,
             env,
             working_dir,
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
         }
     }
 
+    /// Override the cap on captured stdout/stderr in bytes.
+    #[must_use]
+    pub fn with_max_output_bytes(mut self, max_output_bytes: usize) -> Self {
+        self.max_output_bytes = max_output_bytes;
+        self
+    }
+
     /// Create from a [`ShellHookConfig`].
     ///
     /// Config-based hooks (from `moltis.toml`) don't have a hook directory,
@@ -71,6 +89,7 @@ impl ShellHookHandler {
             config.env.clone(),
             None,
         )
+        .with_max_output_bytes(config.max_output_bytes)
     }
 }
 
@@ -111,28 +130,65 @@ impl HookHandler for ShellHookHandler {
             .spawn()
             .with_context(|| format!("failed to spawn hook command: {}", self.command))?;
 
-        // Write payload to stdin (ignore broken pipe if child doesn't read it).
-        if let Some(mut stdin) = child.stdin.take()
-            && let Err(e) = stdin.write_all(payload_json.as_bytes()).await
-            && e.kind() != std::io::ErrorKind::BrokenPipe
-        {
-            return Err(e.into());
-        }
+        let stdin = child.stdin.take();
+        let stdout_pipe = child.stdout.take();
+        let stderr_pipe = child.stderr.take();
+
+        // Write stdin concurrently with reading output so a child that
+        // fills its output pipe before draining stdin can't deadlock us
+        // (ignore broken pipe if the child doesn't read stdin).
+        let write_stdin = async {
+            if let Some(mut stdin) = stdin
+                && let Err(e) = stdin.write_all(payload_json.as_bytes()).await
+                && e.kind() != std::io::ErrorKind::BrokenPipe
+            {
+                return Err(e);
+            }
+            Ok(())
+        };
 
         // Wait with timeout.
-        let output = tokio::time::timeout(self.timeout, child.wait_with_output())
-            .await
-            .with_context(|| {
-                format!(
-                    "hook '{}' timed out after {:?}",
-                    self.hook_name, self.timeout
+        let cap = self.max_output_bytes;
+        let (stdin_res, stdout_res, stderr_res, status) = tokio::time::timeout(
+            self.timeout,
+            async {
+                tokio::join!(
+                    write_stdin,
+                    read_capped(stdout_pipe, cap),
+                    read_capped(stderr_pipe, cap),
+                    child.wait(),
                 )
-            })?
-            .with_context(|| format!("hook '{}' failed to complete", self.hook_name))?;
+            },
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "hook '{}' timed out after {:?}",
+                self.hook_name, self.timeout
+            )
+        })?;
+
+        stdin_res.with_context(|| format!("hook '{}' stdin write failed", self.hook_name))?;
+        let (stdout_bytes, stdout_dropped) = stdout_res
+            .with_context(|| format!("hook '{}' stdout read failed", self.hook_name))?;
+        let (stderr_bytes, stderr_dropped) = stderr_res
+            .with_context(|| format!("hook '{}' stderr read failed", self.hook_name))?;
+        let status =
+            status.with_context(|| format!("hook '{}' failed to complete", self.hook_name))?;
+
+        if stdout_dropped > 0 || stderr_dropped > 0 {
+            warn!(
+                hook = %self.hook_name,
+                max_output_bytes = cap,
+                stdout_dropped,
+                stderr_dropped,
+                "hook output exceeded cap, truncated"
+            );
+        }
 
-        let exit_code = output.status.code().unwrap_or(-1);
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
+        let exit_code = status.code().unwrap_or(-1);
+        let stdout = String::from_utf8_lossy(&stdout_bytes);
+        let stderr = String::from_utf8_lossy(&stderr_bytes);
 
         debug!(
             hook = %self.hook_name,
@@ -166,6 +222,16 @@ impl HookHandler for ShellHookHandler {
         }
 
         match serde_json::from_str::<ShellHookResponse>(stdout_trimmed) {
+            Ok(resp) if resp.action == "retry" => {
+                if let Some(after_ms) = resp.after_ms {
+                    Ok(HookAction::Retry {
+                        after: Duration::from_millis(after_ms),
+                    })
+                } else {
+                    warn!(hook = %self.hook_name, "retry action without after_ms, continuing");
+                    Ok(HookAction::Continue)
+                }
+            },
             Ok(resp) if resp.action == "modify" => {
                 if let Some(data) = resp.data {
                     Ok(HookAction::ModifyPayload(data))
@@ -183,6 +249,31 @@ impl HookHandler for ShellHookHandler {
     }
 }
 
+/// Read a pipe to EOF, keeping at most `cap` bytes. The pipe is drained
+/// past the cap (bytes are counted and discarded) so the child never
+/// blocks on a full pipe. Returns the captured bytes and the dropped count.
+async fn read_capped<R: AsyncRead + Unpin>(
+    reader: Option<R>,
+    cap: usize,
+) -> std::io::Result<(Vec<u8>, u64)> {
+    let Some(mut reader) = reader else {
+        return Ok((Vec::new(), 0));
+    };
+
+    let mut captured = Vec::new();
+    let mut dropped: u64 = 0;
+    let mut chunk = [0u8; 8192];
+    loop {
+        let n = reader.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok((captured, dropped));
+        }
+        let keep = n.min(cap.saturating_sub(captured.len()));
+        captured.extend_from_slice(&chunk[..keep]);
+        dropped += (n - keep) as u64;
+    }
+}
+
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 #[cfg(test)]
 mod tests {
@@ -404,10 +495,89 @@ mod tests {
             events: vec![HookEvent::BeforeToolCall],
             timeout: 3,
             env: HashMap::new(),
+            max_output_bytes: 4096,
         };
         let handler = ShellHookHandler::from_config(&config);
         assert_eq!(handler.name(), "test");
         assert_eq!(handler.events(), &[HookEvent::BeforeToolCall]);
         assert_eq!(handler.timeout, Duration::from_secs(3));
+        assert_eq!(handler.max_output_bytes, 4096);
+    }
+
+    #[tokio::test]
+    async fn shell_hook_retry_response() {
+        let handler = ShellHookHandler::new(
+            "test-retry",
+            r#"echo '{"action":"retry","after_ms":250}'"#,
+            vec![HookEvent::SessionStart],
+            Duration::from_secs(5),
+            HashMap::new(),
+            None,
+        );
+        let result = handler
+            .handle(HookEvent::SessionStart, &test_payload())
+            .await
+            .unwrap();
+        match result {
+            HookAction::Retry { after } => assert_eq!(after, Duration::from_millis(250)),
+            _ => panic!("expected Retry, got: {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn shell_hook_retry_without_after_ms_continues() {
+        let handler = ShellHookHandler::new(
+            "test-retry-bad",
+            r#"echo '{"action":"retry"}'"#,
+            vec![HookEvent::SessionStart],
+            Duration::from_secs(5),
+            HashMap::new(),
+            None,
+        );
+        let result = handler
+            .handle(HookEvent::SessionStart, &test_payload())
+            .await
+            .unwrap();
+        assert!(matches!(result, HookAction::Continue));
+    }
+
+    #[tokio::test]
+    async fn shell_hook_output_over_cap_is_truncated() {
+        let handler = ShellHookHandler::new(
+            "test-cap",
+            // ~64 KiB of output, far over the 1 KiB cap.
+            "head -c 65536 /dev/zero | tr '\\0' 'x'",
+            vec![HookEvent::SessionStart],
+            Duration::from_secs(5),
+            HashMap::new(),
+            None,
+        )
+        .with_max_output_bytes(1024);
+        let result = handler
+            .handle(HookEvent::SessionStart, &test_payload())
+            .await
+            .unwrap();
+        // Truncated non-JSON stdout still resolves to Continue.
+        assert!(matches!(result, HookAction::Continue));
+    }
+
+    #[tokio::test]
+    async fn shell_hook_large_output_does_not_deadlock_stdin() {
+        // The child floods stdout before reading stdin; with a piped-only
+        // read after stdin write this would deadlock on full pipes.
+        let handler = ShellHookHandler::new(
+            "test-flood",
+            "head -c 262144 /dev/zero | tr '\\0' 'y'; cat > /dev/null",
+            vec![HookEvent::SessionStart],
+            Duration::from_secs(5),
+            HashMap::new(),
+            None,
+        )
+        .with_max_output_bytes(1024);
+        let result = handler
+            .handle(HookEvent::SessionStart, &test_payload())
+            .await
+            .unwrap();
+        assert!(matches!(result, HookAction::Continue));
     }
 }
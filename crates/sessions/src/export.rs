@@ -0,0 +1,198 @@
+//! Transcript export for sessions.
+//!
+//! Renders a session's messages (read via [`SessionStore`]) as markdown
+//! for sharing or as JSON for archival/programmatic use.
+
+use {
+    crate::{
+        message::{MessageContent, PersistedMessage},
+        store::SessionStore,
+    },
+    anyhow::Result,
+    serde::{Deserialize, Serialize},
+};
+
+/// Output format for a transcript export.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+/// Export a session's transcript in the requested format.
+///
+/// Markdown renders one section per message with role, timestamp, and any
+/// tool calls; JSON is the pretty-printed array of persisted messages
+/// (raw storage shape, stable for tooling).
+pub async fn export_transcript(
+    store: &SessionStore,
+    key: &str,
+    format: TranscriptFormat,
+) -> Result<String> {
+    match format {
+        TranscriptFormat::Json => {
+            let messages = store.read(key).await?;
+            Ok(serde_json::to_string_pretty(&messages)?)
+        },
+        TranscriptFormat::Markdown => {
+            let messages = store.read_typed(key).await?;
+            Ok(render_markdown(key, &messages))
+        },
+    }
+}
+
+fn render_markdown(key: &str, messages: &[PersistedMessage]) -> String {
+    let mut out = format!("# Transcript: {key}\n");
+    for message in messages {
+        match message {
+            PersistedMessage::System { content, created_at } => {
+                push_section(&mut out, "System", *created_at);
+                out.push_str(content);
+                out.push('\n');
+            },
+            PersistedMessage::Notice { content, created_at } => {
+                push_section(&mut out, "Notice", *created_at);
+                out.push_str(content);
+                out.push('\n');
+            },
+            PersistedMessage::User {
+                content,
+                created_at,
+                ..
+            } => {
+                push_section(&mut out, "User", *created_at);
+                match content {
+                    MessageContent::Text(text) => out.push_str(text),
+                    MessageContent::Multimodal(blocks) => {
+                        let text: Vec<&str> = blocks
+                            .iter()
+                            .map(|b| match b {
+                                crate::message::ContentBlock::Text { text } => text.as_str(),
+                                crate::message::ContentBlock::ImageUrl { .. } => "[image]",
+                            })
+                            .collect();
+                        out.push_str(&text.join("\n"));
+                    },
+                }
+                out.push('\n');
+            },
+            PersistedMessage::Assistant {
+                content,
+                created_at,
+                tool_calls,
+                ..
+            } => {
+                push_section(&mut out, "Assistant", *created_at);
+                out.push_str(content);
+                out.push('\n');
+                for call in tool_calls.iter().flatten() {
+                    out.push_str(&format!(
+                        "- tool call `{}` ({})\n",
+                        call.function.name, call.function.arguments
+                    ));
+                }
+            },
+            PersistedMessage::Tool {
+                tool_call_id,
+                content,
+                created_at,
+            } => {
+                push_section(&mut out, "Tool", *created_at);
+                out.push_str(&format!("`{tool_call_id}`: {content}\n"));
+            },
+            PersistedMessage::ToolResult {
+                tool_name,
+                success,
+                error,
+                created_at,
+                ..
+            } => {
+                push_section(&mut out, "Tool result", *created_at);
+                let status = if *success { "ok" } else { "failed" };
+                out.push_str(&format!("`{tool_name}`: {status}"));
+                if let Some(error) = error {
+                    out.push_str(&format!(" — {error}"));
+                }
+                out.push('\n');
+            },
+        }
+    }
+    out
+}
+
+fn push_section(out: &mut String, role: &str, created_at: Option<u64>) {
+    out.push_str(&format!("\n## {role}"));
+    if let Some(ts) = created_at.and_then(format_timestamp) {
+        out.push_str(&format!(" — {ts}"));
+    }
+    out.push_str("\n\n");
+}
+
+fn format_timestamp(ms: u64) -> Option<String> {
+    time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(ms) * 1_000_000)
+        .ok()
+        .map(|dt| dt.to_string())
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn store_with_two_messages() -> (tempfile::TempDir, SessionStore) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SessionStore::new(dir.path().to_path_buf());
+        store
+            .append("agent:test:main", &PersistedMessage::user("hello").to_value())
+            .await
+            .unwrap();
+        store
+            .append(
+                "agent:test:main",
+                &PersistedMessage::assistant("hi there", "gpt-4o", "openai", 10, 5, None)
+                    .to_value(),
+            )
+            .await
+            .unwrap();
+        (dir, store)
+    }
+
+    #[tokio::test]
+    async fn exports_markdown_with_roles_in_order() {
+        let (_dir, store) = store_with_two_messages().await;
+        let md = export_transcript(&store, "agent:test:main", TranscriptFormat::Markdown)
+            .await
+            .unwrap();
+        assert!(md.starts_with("# Transcript: agent:test:main"));
+        let user_pos = md.find("## User").unwrap();
+        let assistant_pos = md.find("## Assistant").unwrap();
+        assert!(user_pos < assistant_pos);
+        assert!(md.contains("hello"));
+        assert!(md.contains("hi there"));
+    }
+
+    #[tokio::test]
+    async fn exports_json_with_roles_in_order() {
+        let (_dir, store) = store_with_two_messages().await;
+        let json = export_transcript(&store, "agent:test:main", TranscriptFormat::Json)
+            .await
+            .unwrap();
+        let messages: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0]["role"], "user");
+        assert_eq!(messages[1]["role"], "assistant");
+        assert_eq!(messages[1]["content"], "hi there");
+    }
+
+    #[tokio::test]
+    async fn empty_session_exports_header_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SessionStore::new(dir.path().to_path_buf());
+        let md = export_transcript(&store, "agent:test:main", TranscriptFormat::Markdown)
+            .await
+            .unwrap();
+        assert_eq!(md.trim(), "# Transcript: agent:test:main");
+    }
+}
@@ -32,3 +32,143 @@ impl SessionKey {
         ))
     }
 }
+
+/// Canonical channel-derived session key: `channel:account:scope[:thread]`.
+///
+/// Channel plugins historically built key strings with implicit formats,
+/// letting them drift. This structured form is the single builder/parser
+/// for channel-scoped keys; `Display` and `FromStr` round-trip.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChannelSessionKey {
+    /// Channel identifier (e.g. "telegram").
+    pub channel: String,
+    /// Account ID within the channel.
+    pub account: String,
+    /// Conversation scope (peer ID, group ID, or "main").
+    pub scope: String,
+    /// Optional thread within the scope.
+    pub thread: Option<String>,
+}
+
+impl ChannelSessionKey {
+    pub fn new(
+        channel: impl Into<String>,
+        account: impl Into<String>,
+        scope: impl Into<String>,
+    ) -> Self {
+        Self {
+            channel: channel.into(),
+            account: account.into(),
+            scope: scope.into(),
+            thread: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_thread(mut self, thread: impl Into<String>) -> Self {
+        self.thread = Some(thread.into());
+        self
+    }
+
+    /// Whether a raw session key belongs to `channel` under the canonical
+    /// grammar.
+    ///
+    /// Policy code historically tested raw prefixes (`key.starts_with(
+    /// "telegram:")`); this parses the key instead so non-channel keys
+    /// (`agent:...`, malformed strings) can never false-match a channel.
+    #[must_use]
+    pub fn matches_channel(key: &str, channel: &str) -> bool {
+        key.parse::<Self>().is_ok_and(|k| k.channel == channel)
+    }
+}
+
+impl std::fmt::Display for ChannelSessionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.channel, self.account, self.scope)?;
+        if let Some(thread) = &self.thread {
+            write!(f, ":{thread}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ChannelSessionKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(4, ':');
+        let (Some(channel), Some(account), Some(scope)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            anyhow::bail!("session key must have at least channel:account:scope: {s:?}");
+        };
+        let thread = parts.next();
+        if channel.is_empty()
+            || account.is_empty()
+            || scope.is_empty()
+            || thread.is_some_and(str::is_empty)
+        {
+            anyhow::bail!("session key has empty component: {s:?}");
+        }
+        Ok(Self {
+            channel: channel.to_string(),
+            account: account.to_string(),
+            scope: scope.to_string(),
+            thread: thread.map(str::to_string),
+        })
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_without_thread() {
+        let key = ChannelSessionKey::new("telegram", "acct-1", "peer-42");
+        let parsed: ChannelSessionKey = key.to_string().parse().unwrap();
+        assert_eq!(parsed, key);
+        assert_eq!(key.to_string(), "telegram:acct-1:peer-42");
+    }
+
+    #[test]
+    fn round_trips_with_thread() {
+        let key = ChannelSessionKey::new("slack", "ws", "C123").with_thread("171234.5678");
+        let parsed: ChannelSessionKey = key.to_string().parse().unwrap();
+        assert_eq!(parsed, key);
+        assert_eq!(key.to_string(), "slack:ws:C123:171234.5678");
+    }
+
+    #[test]
+    fn matches_channel_agrees_with_policy_prefix_checks() {
+        // Deterministic chat keys and thread-scoped keys both match.
+        assert!(ChannelSessionKey::matches_channel(
+            "telegram:bot1:12345",
+            "telegram"
+        ));
+        assert!(ChannelSessionKey::matches_channel(
+            "telegram:bot1:group:-100999",
+            "telegram"
+        ));
+        // Other channels, agent keys, and malformed keys do not.
+        assert!(!ChannelSessionKey::matches_channel(
+            "telegram:bot1:12345",
+            "discord"
+        ));
+        assert!(!ChannelSessionKey::matches_channel(
+            "agent:main:main",
+            "telegram"
+        ));
+        assert!(!ChannelSessionKey::matches_channel("telegram:", "telegram"));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_keys() {
+        assert!("".parse::<ChannelSessionKey>().is_err());
+        assert!("telegram".parse::<ChannelSessionKey>().is_err());
+        assert!("telegram:acct".parse::<ChannelSessionKey>().is_err());
+        assert!("telegram::scope".parse::<ChannelSessionKey>().is_err());
+        assert!("telegram:acct:scope:".parse::<ChannelSessionKey>().is_err());
+    }
+}
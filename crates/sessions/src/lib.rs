@@ -5,6 +5,7 @@
 //! with file locking for concurrent access.
 
 pub mod compaction;
+pub mod export;
 pub mod key;
 pub mod message;
 pub mod metadata;
@@ -12,7 +13,7 @@ pub mod state_store;
 pub mod store;
 
 pub use {
-    key::SessionKey,
+    key::{ChannelSessionKey, SessionKey},
     message::{ContentBlock, MessageContent, PersistedMessage},
     store::SearchResult,
 };
@@ -323,6 +323,11 @@ impl SqliteSessionMetadata {
         Ok(())
     }
 
+    /// Cheap connectivity check for readiness probes.
+    pub async fn ping(&self) -> bool {
+        sqlx::query("SELECT 1").execute(&self.pool).await.is_ok()
+    }
+
     pub async fn get(&self, key: &str) -> Option<SessionEntry> {
         match sqlx::query_as::<_, SessionRow>("SELECT * FROM sessions WHERE key = ?")
             .bind(key)
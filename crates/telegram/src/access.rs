@@ -96,6 +96,21 @@ pub enum AccessDenied {
     NotMentioned,
 }
 
+impl AccessDenied {
+    /// Machine-readable reason for audit records.
+    #[must_use]
+    pub fn audit_reason(&self) -> &'static str {
+        match self {
+            Self::DmsDisabled => "dms_disabled",
+            Self::NotOnAllowlist => "not_on_allowlist",
+            Self::GroupsDisabled => "groups_disabled",
+            Self::GroupNotOnAllowlist => "group_not_on_allowlist",
+            Self::MentionModeNone => "mention_mode_none",
+            Self::NotMentioned => "not_mentioned",
+        }
+    }
+}
+
 impl std::fmt::Display for AccessDenied {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -11,7 +11,9 @@ use {
     tracing::{debug, error, info, warn},
 };
 
-use moltis_channels::{ChannelEventSink, message_log::MessageLog};
+use moltis_channels::{
+    ChannelEventSink, audit::AuditLog, command::CommandRegistry, message_log::MessageLog,
+};
 
 use crate::{
     config::TelegramAccountConfig,
@@ -30,6 +32,8 @@ pub async fn start_polling(
     accounts: AccountStateMap,
     message_log: Option<Arc<dyn MessageLog>>,
     event_sink: Option<Arc<dyn ChannelEventSink>>,
+    audit_log: Option<Arc<dyn AuditLog>>,
+    command_registry: Option<Arc<CommandRegistry>>,
 ) -> anyhow::Result<CancellationToken> {
     // Build bot with a client timeout longer than the long-polling timeout (30s)
     // so the HTTP client doesn't abort the request before Telegram responds.
@@ -55,6 +59,7 @@ pub async fn start_polling(
         BotCommand::new("clear", "Clear session history"),
         BotCommand::new("compact", "Compact session (summarize)"),
         BotCommand::new("context", "Show session context info"),
+        BotCommand::new("stop", "Cancel the current response"),
         BotCommand::new("help", "Show available commands"),
     ];
     if let Err(e) = bot.set_my_commands(commands).await {
@@ -74,6 +79,12 @@ pub async fn start_polling(
     });
 
     let otp_cooldown = config.otp_cooldown_secs;
+    let limiter = Arc::new(moltis_channels::concurrency::TurnLimiter::new(
+        config.turn_limits.clone(),
+    ));
+    let deduper = Arc::new(moltis_channels::fingerprint::MessageDeduplicator::new(
+        config.dedupe.clone(),
+    ));
     let state = AccountState {
         bot: bot.clone(),
         bot_username,
@@ -83,6 +94,11 @@ pub async fn start_polling(
         cancel: cancel.clone(),
         message_log,
         event_sink,
+        audit_log,
+        limiter,
+        deduper,
+        commands: command_registry,
+        cancellations: Arc::new(moltis_channels::cancellation::TurnCancellationRegistry::new()),
         otp: std::sync::Mutex::new(crate::otp::OtpState::new(otp_cooldown)),
     };
 
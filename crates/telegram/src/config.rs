@@ -1,9 +1,30 @@
 use {
-    moltis_channels::gating::{DmPolicy, GroupPolicy, MentionMode},
+    moltis_channels::{
+        ack::AckStrategy,
+        budget::ResponseBudget,
+        concurrency::TurnLimits,
+        fingerprint::DedupeConfig,
+        gating::{DmPolicy, GroupPolicy, MentionMode},
+        injection_guard::InjectionGuard,
+        language::LanguageRouting,
+        media_policy::MediaPolicy,
+        store::ConfigMigrationStep,
+        template::OutboundTemplate,
+        tool_trace::ToolTraceConfig,
+        truncation::InboundTruncation,
+    },
     secrecy::{ExposeSecret, Secret},
     serde::{Deserialize, Serialize},
 };
 
+/// Migration steps for stored Telegram account configs.
+///
+/// Append a step here whenever a config field is renamed or reshaped;
+/// the store applies pending steps on load (see
+/// `moltis_channels::store::migrate_config`). All current fields
+/// deserialize from the original (version 0) shape, so no steps exist yet.
+pub const CONFIG_MIGRATIONS: &[ConfigMigrationStep] = &[];
+
 /// How streaming responses are delivered.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -72,6 +93,50 @@ pub struct TelegramAccountConfig {
     /// Send bot responses as Telegram replies to the user's message.
     /// When false (default), responses are sent as standalone messages.
     pub reply_to_message: bool,
+
+    /// Truncation policy for very long inbound messages. Applies to the
+    /// agent turn input only; the message log keeps the full original text.
+    pub inbound_truncation: InboundTruncation,
+
+    /// Size/MIME policy for inbound attachments (photos, voice, audio).
+    /// The default allows everything; rejected media gets an explanatory
+    /// reply instead of being dispatched to the agent.
+    pub media_policy: MediaPolicy,
+
+    /// Progress feedback while an agent turn runs (typing indicator or a
+    /// placeholder message removed on completion). Off by default.
+    pub ack_strategy: AckStrategy,
+
+    /// Cap on concurrent agent turns for this account, with a policy for
+    /// overflow (queue, drop, or reject with a busy notice).
+    pub turn_limits: TurnLimits,
+
+    /// Duplicate-delivery suppression for bridged setups. Off by default.
+    pub dedupe: DedupeConfig,
+
+    /// Rule-based prompt-injection screening of the turn input. Off by
+    /// default; when enabled the policy decides whether matching messages
+    /// are flagged, sanitized, or refused.
+    pub injection_guard: InjectionGuard,
+
+    /// Pre-send cap on outbound response length. Off by default; when
+    /// enabled the overflow strategy decides whether an over-budget
+    /// response is truncated, uploaded as a file, or split.
+    pub response_budget: ResponseBudget,
+
+    /// Opt-in wrapper around outbound responses using
+    /// `{agent}`/`{response}`/`{footer}` placeholders; the prefix lands on
+    /// the first message of a chunked response, the footer on the last.
+    pub outbound_template: OutboundTemplate,
+
+    /// Heuristic inbound language detection. Off by default; when enabled
+    /// the detected code annotates the dispatched event, and a model
+    /// preset configured for that language overrides `model` for the turn.
+    pub language_routing: LanguageRouting,
+
+    /// How agent tool calls are surfaced in the chat while a turn runs
+    /// (hidden, compact one-liners, or full arguments). Hidden by default.
+    pub tool_trace: ToolTraceConfig,
 }
 
 impl std::fmt::Debug for TelegramAccountConfig {
@@ -109,6 +174,16 @@ impl Default for TelegramAccountConfig {
             otp_self_approval: true,
             otp_cooldown_secs: 300,
             reply_to_message: false,
+            inbound_truncation: InboundTruncation::default(),
+            media_policy: MediaPolicy::default(),
+            ack_strategy: AckStrategy::default(),
+            turn_limits: TurnLimits::default(),
+            dedupe: DedupeConfig::default(),
+            injection_guard: InjectionGuard::default(),
+            response_budget: ResponseBudget::default(),
+            outbound_template: OutboundTemplate::default(),
+            language_routing: LanguageRouting::default(),
+            tool_trace: ToolTraceConfig::default(),
         }
     }
 }
@@ -151,6 +226,43 @@ mod tests {
         assert_eq!(cfg.group_policy, GroupPolicy::Open);
     }
 
+    #[test]
+    fn deserialize_media_policy() {
+        let json = r#"{
+            "token": "123:ABC",
+            "media_policy": {
+                "max_mb": 5,
+                "blocked_mime_types": ["video/*"]
+            }
+        }"#;
+        let cfg: TelegramAccountConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(cfg.media_policy.max_mb, Some(5));
+        assert_eq!(cfg.media_policy.blocked_mime_types, vec!["video/*"]);
+        // Absent field keeps the allow-everything default.
+        let bare: TelegramAccountConfig = serde_json::from_str(r#"{"token": "t"}"#).unwrap();
+        assert_eq!(bare.media_policy, MediaPolicy::default());
+    }
+
+    #[test]
+    fn deserialize_injection_guard() {
+        let json = r#"{
+            "token": "123:ABC",
+            "injection_guard": {
+                "enabled": true,
+                "policy": "block"
+            }
+        }"#;
+        let cfg: TelegramAccountConfig = serde_json::from_str(json).unwrap();
+        assert!(cfg.injection_guard.enabled);
+        assert_eq!(
+            cfg.injection_guard.policy,
+            moltis_channels::injection_guard::InjectionPolicy::Block
+        );
+        // Absent field keeps the guard disabled.
+        let bare: TelegramAccountConfig = serde_json::from_str(r#"{"token": "t"}"#).unwrap();
+        assert!(!bare.injection_guard.enabled);
+    }
+
     #[test]
     fn serialize_roundtrip() {
         let cfg = TelegramAccountConfig {
@@ -15,7 +15,14 @@ use {
 use {
     moltis_channels::{
         ChannelAttachment, ChannelEvent, ChannelMessageKind, ChannelMessageMeta, ChannelOutbound,
-        ChannelReplyTarget, ChannelType, message_log::MessageLogEntry,
+        ChannelReplyTarget, ChannelType,
+        ack::{AckToken, begin_ack, end_ack},
+        audit::AuditRecord,
+        command::CommandInvocation,
+        concurrency::TurnAdmission,
+        injection_guard::GuardOutcome,
+        media_pipeline::{MediaPipeline, RawAttachment},
+        message_log::MessageLogEntry,
     },
     moltis_common::types::ChatType,
 };
@@ -65,7 +72,18 @@ pub async fn handle_message_direct(
         return Ok(());
     }
 
-    let (config, bot_username, outbound, message_log, event_sink) = {
+    let (
+        config,
+        bot_username,
+        outbound,
+        message_log,
+        event_sink,
+        audit_log,
+        limiter,
+        deduper,
+        commands,
+        cancellations,
+    ) = {
         let accts = accounts.read().unwrap_or_else(|e| e.into_inner());
         let state = match accts.get(account_id) {
             Some(s) => s,
@@ -80,9 +98,23 @@ pub async fn handle_message_direct(
             Arc::clone(&state.outbound),
             state.message_log.clone(),
             state.event_sink.clone(),
+            state.audit_log.clone(),
+            Arc::clone(&state.limiter),
+            Arc::clone(&state.deduper),
+            state.commands.clone(),
+            Arc::clone(&state.cancellations),
         )
     };
 
+    // Inbound processing continues without these, but make the
+    // degradation visible once instead of silently dropping records.
+    if message_log.is_none() {
+        moltis_channels::degraded::warn_once_missing("telegram", account_id, "message_log");
+    }
+    if event_sink.is_none() {
+        moltis_channels::degraded::warn_once_missing("telegram", account_id, "event_sink");
+    }
+
     let (chat_type, group_id) = classify_chat(&msg);
     let peer_id = msg
         .from
@@ -138,6 +170,35 @@ pub async fn handle_message_direct(
     );
     let access_granted = access_result.is_ok();
 
+    // Record the gating decision in the audit trail (both outcomes).
+    if let Some(ref audit) = audit_log {
+        let chat_type_str = match chat_type {
+            ChatType::Dm => "dm",
+            ChatType::Group => "group",
+            ChatType::Channel => "channel",
+        };
+        let record = AuditRecord {
+            id: 0,
+            account_id: account_id.to_string(),
+            channel_type: ChannelType::Telegram.to_string(),
+            peer_id: peer_id.clone(),
+            chat_type: chat_type_str.into(),
+            allowed: access_granted,
+            reason: access_result
+                .as_ref()
+                .err()
+                .map_or("allowed", AccessDenied::audit_reason)
+                .to_string(),
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs() as i64,
+        };
+        if let Err(e) = audit.record(record).await {
+            warn!(account_id, "failed to record audit entry: {e}");
+        }
+    }
+
     // Log every inbound message (before returning on denial).
     if let Some(ref log) = message_log {
         let chat_type_str = match chat_type {
@@ -209,6 +270,19 @@ pub async fn handle_message_direct(
 
     debug!(account_id, "handler: access granted");
 
+    // Suppress duplicate deliveries (bridged setups) before any
+    // downloads or session dispatch.
+    if let Some(ref t) = text
+        && deduper.observe_now(&peer_id, t)
+    {
+        info!(account_id, peer_id, "duplicate message suppressed");
+        return Ok(());
+    }
+
+    // Shared normalization (MIME sniffing + media policy) for all
+    // downloaded attachments.
+    let media_pipeline = MediaPipeline::new(config.media_policy.clone());
+
     // Check for voice/audio messages and transcribe them
     let (body, attachments) = if let Some(voice_file) = extract_voice_file(&msg) {
         // If STT is not configured, reply with guidance and do not dispatch to the LLM.
@@ -233,14 +307,40 @@ pub async fn handle_message_direct(
         if let Some(ref sink) = event_sink {
             match download_telegram_file(bot, &voice_file.file_id).await {
                 Ok(audio_data) => {
+                    let raw = RawAttachment {
+                        declared_mime: Some(format!("audio/{}", voice_file.format)),
+                        filename: None,
+                        data: audio_data,
+                    };
+                    let normalized = match media_pipeline.normalize(raw).await {
+                        Ok(n) => n,
+                        Err(rejected) => {
+                            info!(account_id, %rejected, "voice message rejected by media policy");
+                            if let Err(e) = outbound
+                                .send_text(
+                                    account_id,
+                                    &msg.chat.id.0.to_string(),
+                                    &format!("Can't process this voice message: {rejected}"),
+                                    None,
+                                )
+                                .await
+                            {
+                                warn!(account_id, "failed to send media rejection notice: {e}");
+                            }
+                            return Ok(());
+                        },
+                    };
                     debug!(
                         account_id,
                         file_id = %voice_file.file_id,
                         format = %voice_file.format,
-                        size = audio_data.len(),
+                        size = normalized.data.len(),
                         "downloaded voice file, transcribing"
                     );
-                    match sink.transcribe_voice(&audio_data, &voice_file.format).await {
+                    match sink
+                        .transcribe_voice(&normalized.data, &voice_file.format)
+                        .await
+                    {
                         Ok(transcribed) => {
                             debug!(
                                 account_id,
@@ -289,41 +389,62 @@ pub async fn handle_message_direct(
         // Handle photo messages - download and send as multimodal content
         match download_telegram_file(bot, &photo_file.file_id).await {
             Ok(image_data) => {
+                let raw = RawAttachment {
+                    declared_mime: Some(photo_file.media_type.clone()),
+                    filename: None,
+                    data: image_data,
+                };
+                let normalized = match media_pipeline.normalize(raw).await {
+                    Ok(n) => n,
+                    Err(rejected) => {
+                        info!(account_id, %rejected, "photo rejected by media policy");
+                        if let Err(e) = outbound
+                            .send_text(
+                                account_id,
+                                &msg.chat.id.0.to_string(),
+                                &format!("Can't process this photo: {rejected}"),
+                                None,
+                            )
+                            .await
+                        {
+                            warn!(account_id, "failed to send media rejection notice: {e}");
+                        }
+                        return Ok(());
+                    },
+                };
                 debug!(
                     account_id,
                     file_id = %photo_file.file_id,
-                    size = image_data.len(),
+                    size = normalized.data.len(),
                     "downloaded photo"
                 );
 
                 // Optimize image for LLM consumption (resize if needed, compress)
-                let (final_data, media_type) = match moltis_media::image_ops::optimize_for_llm(
-                    &image_data,
+                let attachment = match moltis_media::image_ops::optimize_for_llm(
+                    &normalized.data,
                     None,
                 ) {
                     Ok(optimized) => {
                         if optimized.was_resized {
                             info!(
                                 account_id,
-                                original_size = image_data.len(),
+                                original_size = normalized.data.len(),
                                 final_size = optimized.data.len(),
                                 original_dims = %format!("{}x{}", optimized.original_width, optimized.original_height),
                                 final_dims = %format!("{}x{}", optimized.final_width, optimized.final_height),
                                 "resized image for LLM"
                             );
                         }
-                        (optimized.data, optimized.media_type)
+                        ChannelAttachment {
+                            media_type: optimized.media_type,
+                            data: optimized.data,
+                        }
                     },
                     Err(e) => {
                         warn!(account_id, error = %e, "failed to optimize image, using original");
-                        (image_data, photo_file.media_type)
+                        normalized.into_channel_attachment()
                     },
                 };
-
-                let attachment = ChannelAttachment {
-                    media_type,
-                    data: final_data,
-                };
                 // Use caption as text, or empty string if no caption
                 let caption = text.clone().unwrap_or_default();
                 (caption, vec![attachment])
@@ -438,6 +559,26 @@ pub async fn handle_message_direct(
         if body.starts_with('/') {
             let cmd_text = body.trim_start_matches('/');
             let cmd = cmd_text.split_whitespace().next().unwrap_or("");
+
+            // "/stop" cancels this chat's in-flight turns locally; it never
+            // reaches the LLM.
+            if cmd == "stop" {
+                let stopped = cancellations
+                    .cancel_prefixed(account_id, &format!("{}:", reply_target.chat_id));
+                let notice = if stopped > 0 {
+                    "Stopped."
+                } else {
+                    "Nothing is running."
+                };
+                if let Err(e) = outbound
+                    .send_text(account_id, &reply_target.chat_id, notice, None)
+                    .await
+                {
+                    warn!(account_id, "failed to send stop confirmation: {e}");
+                }
+                return Ok(());
+            }
+
             if should_intercept_slash_command(cmd, cmd_text) {
                 // For /context, send a formatted card with inline keyboard.
                 if cmd == "context" {
@@ -543,7 +684,7 @@ pub async fn handle_message_direct(
                 }
 
                 let response = if cmd == "help" {
-                    "Available commands:\n/new — Start a new session\n/sessions — List and switch sessions\n/model — Switch provider/model\n/sandbox — Toggle sandbox and choose image\n/sh — Enable command mode (/sh off to exit)\n/clear — Clear session history\n/compact — Compact session (summarize)\n/context — Show session context info\n/help — Show this help".to_string()
+                    "Available commands:\n/new — Start a new session\n/sessions — List and switch sessions\n/model — Switch provider/model\n/sandbox — Toggle sandbox and choose image\n/sh — Enable command mode (/sh off to exit)\n/clear — Clear session history\n/compact — Compact session (summarize)\n/context — Show session context info\n/stop — Cancel the current response\n/help — Show this help".to_string()
                 } else {
                     match sink.dispatch_command(cmd_text, reply_target.clone()).await {
                         Ok(msg) => msg,
@@ -564,6 +705,48 @@ pub async fn handle_message_direct(
                 }
                 return Ok(());
             }
+
+            // Host-registered commands run only when no built-in claims the
+            // name; unregistered names still fall through to the LLM.
+            if let Some(ref registry) = commands
+                && let Some(invocation) =
+                    CommandInvocation::parse_prefixed(&body, "/", account_id, &peer_id)
+                && registry.get(&invocation.name).is_some()
+            {
+                let response = match registry.dispatch(&invocation).await {
+                    Ok(msg) => msg,
+                    Err(e) => format!("Error: {e}"),
+                };
+                if let Err(e) = outbound
+                    .send_text(account_id, &reply_target.chat_id, &response, None)
+                    .await
+                {
+                    warn!(account_id, "failed to send command response: {e}");
+                }
+                return Ok(());
+            }
+        }
+
+        // Detected inbound language annotates the event; a model preset
+        // configured for that language overrides the account default.
+        let language = config
+            .language_routing
+            .detect(&body)
+            .map(ToString::to_string);
+        let model = language
+            .as_deref()
+            .and_then(|code| config.language_routing.preset_for(code))
+            .map(ToString::to_string)
+            .or_else(|| config.model.clone());
+        if let (Some(code), Some(preset)) = (&language, &model)
+            && config.model.as_ref() != Some(preset)
+        {
+            debug!(
+                account_id,
+                language = %code,
+                model = %preset,
+                "language routing selected model preset"
+            );
         }
 
         let meta = ChannelMessageMeta {
@@ -571,15 +754,128 @@ pub async fn handle_message_direct(
             sender_name: sender_name.clone(),
             username: username.clone(),
             message_kind: message_kind(&msg),
-            model: config.model.clone(),
+            model,
+            language,
         };
 
-        if attachments.is_empty() {
-            sink.dispatch_to_chat(&body, reply_target, meta).await;
-        } else {
-            sink.dispatch_to_chat_with_attachments(&body, attachments, reply_target, meta)
-                .await;
-        }
+        // The message log above stores the full original text; truncation
+        // only limits what reaches the agent turn.
+        let body = config.inbound_truncation.apply(&body).into_owned();
+
+        // Screen the turn input for prompt-injection phrases. The message
+        // log keeps the original text regardless of policy.
+        let body = match config.injection_guard.scan(&body) {
+            GuardOutcome::Clean => body,
+            GuardOutcome::Flagged { matches } => {
+                warn!(
+                    account_id,
+                    ?matches,
+                    "inbound message flagged by injection guard"
+                );
+                body
+            },
+            GuardOutcome::Stripped { text, matches } => {
+                warn!(
+                    account_id,
+                    ?matches,
+                    "injection patterns stripped from inbound message"
+                );
+                text
+            },
+            GuardOutcome::Blocked { matches } => {
+                warn!(
+                    account_id,
+                    ?matches,
+                    "inbound message blocked by injection guard"
+                );
+                if let Err(e) = outbound
+                    .send_text(
+                        account_id,
+                        &reply_target.chat_id,
+                        "This message can't be processed here.",
+                        None,
+                    )
+                    .await
+                {
+                    warn!(account_id, "failed to send injection-block notice: {e}");
+                }
+                return Ok(());
+            },
+        };
+
+        // Run the agent turn in its own task so one long turn doesn't
+        // stall the polling loop; `turn_limits` bounds in-flight turns.
+        let sink = Arc::clone(sink);
+        let outbound = Arc::clone(&outbound);
+        let account = account_id.to_string();
+        let ack_strategy = config.ack_strategy;
+        tokio::spawn(async move {
+            let chat_id = reply_target.chat_id.clone();
+            let _permit = match limiter.admit(&account).await {
+                TurnAdmission::Admitted(permit) => permit,
+                TurnAdmission::Busy => {
+                    if let Err(e) = outbound
+                        .send_text(
+                            &account,
+                            &chat_id,
+                            "I'm still working on earlier messages — please try again in a moment.",
+                            None,
+                        )
+                        .await
+                    {
+                        warn!(account_id = %account, "failed to send busy notice: {e}");
+                    }
+                    return;
+                },
+                TurnAdmission::Dropped => {
+                    info!(account_id = %account, "inbound turn dropped: concurrency cap reached");
+                    return;
+                },
+            };
+
+            // Progress feedback for the duration of the turn.
+            let ack_token = begin_ack(
+                ack_strategy,
+                outbound.as_ref(),
+                &account,
+                &chat_id,
+                reply_target.message_id.as_deref(),
+            )
+            .await
+            .unwrap_or_else(|e| {
+                warn!(account_id = %account, "failed to start turn ack: {e}");
+                AckToken::default()
+            });
+
+            // Track the turn keyed by its triggering message so "/stop" can
+            // abandon everything in-flight for this chat without clobbering
+            // concurrent turns the limiter admitted.
+            let turn_id = format!(
+                "{chat_id}:{}",
+                reply_target.message_id.as_deref().unwrap_or_default()
+            );
+            let cancel_token = cancellations.register(&account, &turn_id);
+            let dispatch = async {
+                if attachments.is_empty() {
+                    sink.dispatch_to_chat(&body, reply_target, meta).await;
+                } else {
+                    sink.dispatch_to_chat_with_attachments(&body, attachments, reply_target, meta)
+                        .await;
+                }
+            };
+            tokio::select! {
+                () = cancel_token.cancelled() => {
+                    info!(account_id = %account, chat_id = %chat_id, "agent turn cancelled");
+                },
+                () = dispatch => {
+                    cancellations.complete(&account, &turn_id);
+                },
+            }
+
+            if let Err(e) = end_ack(ack_token, outbound.as_ref(), &account, &chat_id).await {
+                warn!(account_id = %account, "failed to clear turn ack: {e}");
+            }
+        });
     }
 
     #[cfg(feature = "metrics")]
@@ -1452,14 +1748,18 @@ async fn download_telegram_file(bot: &Bot, file_id: &str) -> anyhow::Result<Vec<
 
 /// Classify the chat type.
 fn classify_chat(msg: &Message) -> (ChatType, Option<String>) {
+    use moltis_channels::chat_type::{RawChatKind, infer_chat_type};
+
     match msg.chat.kind {
-        teloxide::types::ChatKind::Private(_) => (ChatType::Dm, None),
+        teloxide::types::ChatKind::Private(_) => (infer_chat_type(RawChatKind::Private), None),
         teloxide::types::ChatKind::Public(ref p) => {
             let group_id = msg.chat.id.0.to_string();
-            match p.kind {
-                teloxide::types::PublicChatKind::Channel(_) => (ChatType::Channel, Some(group_id)),
-                _ => (ChatType::Group, Some(group_id)),
-            }
+            let raw = match p.kind {
+                teloxide::types::PublicChatKind::Channel(_) => RawChatKind::Broadcast,
+                teloxide::types::PublicChatKind::Supergroup(_) => RawChatKind::Supergroup,
+                teloxide::types::PublicChatKind::Group(_) => RawChatKind::Group,
+            };
+            (infer_chat_type(raw), Some(group_id))
         },
     }
 }
@@ -1474,23 +1774,6 @@ fn check_bot_mentioned(msg: &Message, bot_username: Option<&str>) -> bool {
     }
 }
 
-/// Build a session key.
-#[allow(dead_code)]
-fn build_session_key(
-    account_id: &str,
-    chat_type: &ChatType,
-    peer_id: &str,
-    group_id: Option<&str>,
-) -> String {
-    match chat_type {
-        ChatType::Dm => format!("telegram:{account_id}:dm:{peer_id}"),
-        ChatType::Group | ChatType::Channel => {
-            let gid = group_id.unwrap_or("unknown");
-            format!("telegram:{account_id}:group:{gid}")
-        },
-    }
-}
-
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 #[cfg(test)]
 mod tests {
@@ -1690,18 +1973,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn session_key_dm() {
-        let key = build_session_key("bot1", &ChatType::Dm, "user123", None);
-        assert_eq!(key, "telegram:bot1:dm:user123");
-    }
-
-    #[test]
-    fn session_key_group() {
-        let key = build_session_key("bot1", &ChatType::Group, "user123", Some("-100999"));
-        assert_eq!(key, "telegram:bot1:group:-100999");
-    }
-
     #[test]
     fn intercepts_shell_mode_control_commands_only() {
         assert!(should_intercept_slash_command("sh", "sh"));
@@ -1825,6 +2096,17 @@ mod tests {
                 cancel: CancellationToken::new(),
                 message_log: None,
                 event_sink: Some(Arc::clone(&sink) as Arc<dyn ChannelEventSink>),
+                audit_log: None,
+                limiter: Arc::new(moltis_channels::concurrency::TurnLimiter::new(
+                    Default::default(),
+                )),
+                deduper: Arc::new(moltis_channels::fingerprint::MessageDeduplicator::new(
+                    Default::default(),
+                )),
+                commands: None,
+                cancellations: Arc::new(
+                    moltis_channels::cancellation::TurnCancellationRegistry::new(),
+                ),
                 otp: Mutex::new(OtpState::new(300)),
             });
         }
@@ -2,7 +2,7 @@ use {
     anyhow::Result,
     async_trait::async_trait,
     base64::Engine,
-    std::{future::Future, time::Duration},
+    std::{borrow::Cow, future::Future, time::Duration},
     teloxide::{
         ApiError, RequestError,
         payloads::{SendLocationSetters, SendMessageSetters, SendVenueSetters},
@@ -13,8 +13,11 @@ use {
 };
 
 use {
-    moltis_channels::plugin::{
-        ChannelOutbound, ChannelStreamOutbound, StreamEvent, StreamReceiver,
+    moltis_channels::{
+        ack::AckAdapter,
+        budget::{BudgetOutcome, ResponseBudget},
+        plugin::{ChannelOutbound, ChannelStreamOutbound, StreamEvent, StreamReceiver},
+        template::OutboundTemplate,
     },
     moltis_common::types::ReplyPayload,
 };
@@ -71,6 +74,22 @@ impl TelegramOutbound {
         }
     }
 
+    fn outbound_template(&self, account_id: &str) -> OutboundTemplate {
+        let accounts = self.accounts.read().unwrap_or_else(|e| e.into_inner());
+        accounts
+            .get(account_id)
+            .map(|s| s.config.outbound_template.clone())
+            .unwrap_or_default()
+    }
+
+    fn response_budget(&self, account_id: &str) -> ResponseBudget {
+        let accounts = self.accounts.read().unwrap_or_else(|e| e.into_inner());
+        accounts
+            .get(account_id)
+            .map(|s| s.config.response_budget.clone())
+            .unwrap_or_default()
+    }
+
     fn stream_send_config(&self, account_id: &str) -> StreamSendConfig {
         let accounts = self.accounts.read().unwrap_or_else(|e| e.into_inner());
         accounts
@@ -275,10 +294,55 @@ impl ChannelOutbound for TelegramOutbound {
         let chat_id = ChatId(to.parse::<i64>()?);
         let rp = self.reply_params(account_id, reply_to);
 
+        // Apply the per-account response budget before chunking.
+        let text: Cow<'_, str> = match self
+            .response_budget(account_id)
+            .apply(text, TELEGRAM_MAX_MESSAGE_LEN)
+        {
+            BudgetOutcome::Unchanged => Cow::Borrowed(text),
+            BudgetOutcome::Truncated(capped) => {
+                info!(
+                    account_id,
+                    chat_id = to,
+                    original_len = text.len(),
+                    "telegram outbound text truncated by response budget"
+                );
+                Cow::Owned(capped)
+            },
+            BudgetOutcome::UploadAsFile { filename, notice } => {
+                info!(
+                    account_id,
+                    chat_id = to,
+                    original_len = text.len(),
+                    "telegram outbound text uploaded as file by response budget"
+                );
+                return self
+                    .send_file(
+                        account_id,
+                        to,
+                        &filename,
+                        text.as_bytes().to_vec(),
+                        "text/markdown",
+                        Some(&notice),
+                    )
+                    .await;
+            },
+            // The split strategy already capped the total length; rejoin and
+            // let the markdown pipeline below do the per-message chunking.
+            BudgetOutcome::Split(parts) => Cow::Owned(parts.concat()),
+        };
+
         // Send typing indicator
         let _ = bot.send_chat_action(chat_id, ChatAction::Typing).await;
 
-        let chunks = markdown::chunk_markdown_html(text, TELEGRAM_MAX_MESSAGE_LEN);
+        // Wrap the chunked response per the account template (prefix on the
+        // first message, footer on the last).
+        let chunks = self
+            .outbound_template(account_id)
+            .apply(markdown::chunk_markdown_html(
+                &text,
+                TELEGRAM_MAX_MESSAGE_LEN,
+            ));
         info!(
             account_id,
             chat_id = to,
@@ -409,6 +473,63 @@ impl ChannelOutbound for TelegramOutbound {
         Ok(())
     }
 
+    async fn send_file(
+        &self,
+        account_id: &str,
+        to: &str,
+        filename: &str,
+        bytes: Vec<u8>,
+        mime: &str,
+        caption: Option<&str>,
+    ) -> Result<()> {
+        let bot = self.get_bot(account_id)?;
+        let chat_id = ChatId(to.parse::<i64>()?);
+
+        info!(
+            account_id,
+            chat_id = to,
+            filename,
+            mime,
+            bytes = bytes.len(),
+            "telegram outbound file send start"
+        );
+
+        // Images go out as photos for inline previews, with a document
+        // fallback when Telegram rejects the dimensions.
+        if mime.starts_with("image/") {
+            let input = InputFile::memory(bytes.clone()).file_name(filename.to_string());
+            let mut req = bot.send_photo(chat_id, input);
+            if let Some(caption) = caption {
+                req = req.caption(caption);
+            }
+            match req.await {
+                Ok(_) => {
+                    info!(account_id, chat_id = to, filename, "telegram outbound file sent as photo");
+                    return Ok(());
+                },
+                Err(e) => {
+                    let err_str = e.to_string();
+                    if !err_str.contains("PHOTO_INVALID_DIMENSIONS")
+                        && !err_str.contains("PHOTO_SAVE_FILE_INVALID")
+                    {
+                        return Err(e.into());
+                    }
+                    debug!(error = %err_str, "photo rejected, retrying as document");
+                },
+            }
+        }
+
+        let input = InputFile::memory(bytes).file_name(filename.to_string());
+        let mut req = bot.send_document(chat_id, input);
+        if let Some(caption) = caption {
+            req = req.caption(caption);
+        }
+        req.await?;
+
+        info!(account_id, chat_id = to, filename, "telegram outbound file sent as document");
+        Ok(())
+    }
+
     async fn send_typing(&self, account_id: &str, to: &str) -> Result<()> {
         let bot = self.get_bot(account_id)?;
         let chat_id = ChatId(to.parse::<i64>()?);
@@ -763,6 +884,41 @@ impl TelegramOutbound {
     }
 }
 
+/// Progress-feedback mapping for [`moltis_channels::ack`].
+///
+/// Typing maps to Telegram's chat action; placeholders to a silent "…"
+/// message deleted when the turn ends. Reactions stay at the no-op
+/// defaults until the bot API wrapper exposes `setMessageReaction`.
+#[async_trait]
+impl AckAdapter for TelegramOutbound {
+    async fn start_typing(&self, account_id: &str, chat_id: &str) -> Result<()> {
+        ChannelOutbound::send_typing(self, account_id, chat_id).await
+    }
+
+    async fn send_placeholder(&self, account_id: &str, chat_id: &str) -> Result<Option<String>> {
+        let bot = self.get_bot(account_id)?;
+        let chat = ChatId(chat_id.parse::<i64>()?);
+        let message = bot
+            .send_message(chat, "\u{2026}")
+            .disable_notification(true)
+            .await?;
+        Ok(Some(message.id.0.to_string()))
+    }
+
+    async fn clear_placeholder(
+        &self,
+        account_id: &str,
+        chat_id: &str,
+        message_id: &str,
+    ) -> Result<()> {
+        let bot = self.get_bot(account_id)?;
+        let chat = ChatId(chat_id.parse::<i64>()?);
+        bot.delete_message(chat, MessageId(message_id.parse::<i32>()?))
+            .await?;
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl ChannelStreamOutbound for TelegramOutbound {
     async fn send_stream(
@@ -14,6 +14,8 @@ use {
 
 use moltis_channels::{
     ChannelEventSink,
+    audit::AuditLog,
+    command::CommandRegistry,
     message_log::MessageLog,
     plugin::{
         ChannelHealthSnapshot, ChannelOutbound, ChannelPlugin, ChannelStatus, ChannelStreamOutbound,
@@ -33,6 +35,8 @@ pub struct TelegramPlugin {
     outbound: TelegramOutbound,
     message_log: Option<Arc<dyn MessageLog>>,
     event_sink: Option<Arc<dyn ChannelEventSink>>,
+    audit_log: Option<Arc<dyn AuditLog>>,
+    command_registry: Option<Arc<CommandRegistry>>,
     probe_cache: RwLock<HashMap<String, (ChannelHealthSnapshot, Instant)>>,
 }
 
@@ -47,6 +51,8 @@ impl TelegramPlugin {
             outbound,
             message_log: None,
             event_sink: None,
+            audit_log: None,
+            command_registry: None,
             probe_cache: RwLock::new(HashMap::new()),
         }
     }
@@ -61,6 +67,18 @@ impl TelegramPlugin {
         self
     }
 
+    /// Record access-control decisions for every inbound message.
+    pub fn with_audit_log(mut self, log: Arc<dyn AuditLog>) -> Self {
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Serve host-registered commands for slash commands no built-in claims.
+    pub fn with_command_registry(mut self, registry: Arc<CommandRegistry>) -> Self {
+        self.command_registry = Some(registry);
+        self
+    }
+
     /// Get a shared reference to the outbound sender (for use outside the plugin).
     pub fn shared_outbound(&self) -> Arc<dyn ChannelOutbound> {
         Arc::new(TelegramOutbound {
@@ -132,12 +150,17 @@ impl ChannelPlugin for TelegramPlugin {
         "Telegram"
     }
 
-    async fn start_account(&mut self, account_id: &str, config: serde_json::Value) -> Result<()> {
-        let tg_config: TelegramAccountConfig = serde_json::from_value(config)?;
-
+    fn validate_config(&self, config: &serde_json::Value) -> Result<()> {
+        let tg_config: TelegramAccountConfig = serde_json::from_value(config.clone())?;
         if tg_config.token.expose_secret().is_empty() {
             return Err(anyhow::anyhow!("telegram bot token is required"));
         }
+        Ok(())
+    }
+
+    async fn start_account(&mut self, account_id: &str, config: serde_json::Value) -> Result<()> {
+        self.validate_config(&config)?;
+        let tg_config: TelegramAccountConfig = serde_json::from_value(config)?;
 
         info!(account_id, "starting telegram account");
 
@@ -147,6 +170,8 @@ impl ChannelPlugin for TelegramPlugin {
             Arc::clone(&self.accounts),
             self.message_log.clone(),
             self.event_sink.clone(),
+            self.audit_log.clone(),
+            self.command_registry.clone(),
         )
         .await?;
 
@@ -197,25 +222,25 @@ impl ChannelStatus for TelegramPlugin {
         };
 
         let result = match bot {
-            Some(bot) => match bot.get_me().await {
-                Ok(me) => ChannelHealthSnapshot {
-                    connected: true,
-                    account_id: account_id.to_string(),
-                    details: Some(format!(
-                        "Bot: @{}",
-                        me.username.as_deref().unwrap_or("unknown")
-                    )),
-                },
-                Err(e) => ChannelHealthSnapshot {
-                    connected: false,
-                    account_id: account_id.to_string(),
-                    details: Some(format!("API error: {e}")),
-                },
+            Some(bot) => {
+                let started = Instant::now();
+                match bot.get_me().await {
+                    Ok(me) => ChannelHealthSnapshot::connected(
+                        account_id,
+                        Some(format!(
+                            "Bot: @{}",
+                            me.username.as_deref().unwrap_or("unknown")
+                        )),
+                    )
+                    .with_latency_ms(started.elapsed().as_millis() as u64),
+                    Err(e) => ChannelHealthSnapshot::disconnected(
+                        account_id,
+                        Some(format!("API error: {e}")),
+                    ),
+                }
             },
-            None => ChannelHealthSnapshot {
-                connected: false,
-                account_id: account_id.to_string(),
-                details: Some("account not started".into()),
+            None => {
+                ChannelHealthSnapshot::disconnected(account_id, Some("account not started".into()))
             },
         };
 
@@ -254,6 +279,15 @@ mod tests {
             cancel,
             message_log: None,
             event_sink: None,
+            audit_log: None,
+            limiter: Arc::new(moltis_channels::concurrency::TurnLimiter::new(
+                Default::default(),
+            )),
+            deduper: Arc::new(moltis_channels::fingerprint::MessageDeduplicator::new(
+                Default::default(),
+            )),
+            commands: None,
+            cancellations: Arc::new(moltis_channels::cancellation::TurnCancellationRegistry::new()),
             otp: std::sync::Mutex::new(OtpState::new(300)),
         }
     }
@@ -364,6 +398,35 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn probe_not_started_reports_structured_disconnect() {
+        let plugin = TelegramPlugin::new();
+        let snap = plugin.probe("missing").await.unwrap();
+        assert!(!snap.connected);
+        assert_eq!(snap.last_error.as_deref(), Some("account not started"));
+        assert!(snap.last_connected_at.is_none());
+        assert!(snap.latency_ms.is_none());
+    }
+
+    #[test]
+    fn validate_config_rejects_empty_token() {
+        let plugin = TelegramPlugin::new();
+        assert!(plugin
+            .validate_config(&serde_json::json!({ "token": "" }))
+            .is_err());
+        assert!(plugin.validate_config(&serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn validate_config_accepts_valid_config() {
+        let plugin = TelegramPlugin::new();
+        let config = serde_json::json!({
+            "token": "test:fake_token_for_unit_tests",
+            "dm_policy": "allowlist",
+        });
+        assert!(plugin.validate_config(&config).is_ok());
+    }
+
     #[test]
     fn update_account_config_nonexistent_account_errors() {
         let plugin = TelegramPlugin::new();
@@ -5,7 +5,11 @@ use std::{
 
 use tokio_util::sync::CancellationToken;
 
-use moltis_channels::{ChannelEventSink, message_log::MessageLog};
+use moltis_channels::{
+    ChannelEventSink, audit::AuditLog, cancellation::TurnCancellationRegistry,
+    command::CommandRegistry, concurrency::TurnLimiter, fingerprint::MessageDeduplicator,
+    message_log::MessageLog,
+};
 
 use crate::{config::TelegramAccountConfig, otp::OtpState, outbound::TelegramOutbound};
 
@@ -22,6 +26,17 @@ pub struct AccountState {
     pub cancel: CancellationToken,
     pub message_log: Option<Arc<dyn MessageLog>>,
     pub event_sink: Option<Arc<dyn ChannelEventSink>>,
+    pub audit_log: Option<Arc<dyn AuditLog>>,
+    /// Bounds concurrent agent turns per `config.turn_limits`.
+    pub limiter: Arc<TurnLimiter>,
+    /// Suppresses duplicate deliveries per `config.dedupe`.
+    pub deduper: Arc<MessageDeduplicator>,
+    /// Host-registered commands; consulted for slash commands that no
+    /// built-in claims.
+    pub commands: Option<Arc<CommandRegistry>>,
+    /// Tokens for in-flight agent turns, keyed by triggering message
+    /// (`"{chat}:{message}"`); `/stop` cancels all of its chat's turns.
+    pub cancellations: Arc<TurnCancellationRegistry>,
     /// In-memory OTP challenges for self-approval (std::sync::Mutex because
     /// all OTP operations are synchronous HashMap lookups, never held across
     /// `.await` points).
@@ -20,6 +20,8 @@ pub mod process;
 pub mod sandbox;
 pub mod sandbox_packages;
 pub mod session_state;
+pub mod sessions_create;
+pub mod sessions_search;
 pub mod skill_tools;
 pub mod spawn_agent;
 pub mod web_fetch;
@@ -12,6 +12,7 @@ use {
     anyhow::Result,
     async_trait::async_trait,
     moltis_config::GeoLocation,
+    moltis_sessions::ChannelSessionKey,
     serde::{Deserialize, Serialize},
     tracing::warn,
 };
@@ -366,7 +367,8 @@ impl moltis_agents::tool_registry::AgentTool for LocationTool {
 
         // No browser connection — try channel-based location request.
         if let Some(session_key) = params.get("_session_key").and_then(|v| v.as_str())
-            && (session_key.starts_with("telegram:") || session_key.starts_with("discord:"))
+            && (ChannelSessionKey::matches_channel(session_key, "telegram")
+                || ChannelSessionKey::matches_channel(session_key, "discord"))
         {
             let result = self.requester.request_channel_location(session_key).await?;
             return match result.location {
@@ -0,0 +1,156 @@
+//! Agent tool for provisioning a named session for delegated work.
+
+use std::sync::Arc;
+
+use {
+    anyhow::Result,
+    async_trait::async_trait,
+    moltis_agents::tool_registry::AgentTool,
+    moltis_sessions::metadata::SqliteSessionMetadata,
+    serde_json::{Value, json},
+};
+
+/// Agent tool that creates a named session, returning its metadata.
+/// Creating a key that already exists is idempotent and returns the
+/// existing entry unchanged.
+pub struct SessionsCreateTool {
+    metadata: Arc<SqliteSessionMetadata>,
+}
+
+impl SessionsCreateTool {
+    pub fn new(metadata: Arc<SqliteSessionMetadata>) -> Self {
+        Self { metadata }
+    }
+}
+
+#[async_trait]
+impl AgentTool for SessionsCreateTool {
+    fn name(&self) -> &str {
+        "sessions_create"
+    }
+
+    fn description(&self) -> &str {
+        "Create a new named session (e.g. to delegate work to). Takes a \
+         session key and an optional label. If the key already exists, \
+         returns the existing session unchanged."
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["key"],
+            "properties": {
+                "key": {
+                    "type": "string",
+                    "description": "Unique session key (e.g. 'session:research-task')"
+                },
+                "label": {
+                    "type": "string",
+                    "description": "Human-readable label for the new session"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, params: Value) -> Result<Value> {
+        let key = params
+            .get("key")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("missing 'key'"))?;
+        if key.chars().any(char::is_whitespace) {
+            anyhow::bail!("session key must not contain whitespace");
+        }
+
+        let label = params
+            .get("label")
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+
+        // Idempotent: an existing key returns its current metadata.
+        if let Some(entry) = self.metadata.get(key).await {
+            return Ok(json!({
+                "sessionKey": entry.key,
+                "id": entry.id,
+                "label": entry.label,
+                "messageCount": entry.message_count,
+                "created": false,
+            }));
+        }
+
+        let entry = self
+            .metadata
+            .upsert(key, label)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to create session: {e}"))?;
+
+        Ok(json!({
+            "sessionKey": entry.key,
+            "id": entry.id,
+            "label": entry.label,
+            "messageCount": entry.message_count,
+            "created": true,
+        }))
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup() -> Arc<SqliteSessionMetadata> {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE IF NOT EXISTS projects (id TEXT PRIMARY KEY)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        SqliteSessionMetadata::init(&pool).await.unwrap();
+        Arc::new(SqliteSessionMetadata::new(pool))
+    }
+
+    #[tokio::test]
+    async fn creates_named_session() {
+        let metadata = setup().await;
+        let tool = SessionsCreateTool::new(Arc::clone(&metadata));
+
+        let result = tool
+            .execute(json!({"key": "session:research", "label": "Research"}))
+            .await
+            .unwrap();
+
+        assert_eq!(result["sessionKey"], "session:research");
+        assert_eq!(result["label"], "Research");
+        assert_eq!(result["created"], true);
+        assert!(metadata.get("session:research").await.is_some());
+    }
+
+    #[tokio::test]
+    async fn existing_key_is_idempotent() {
+        let metadata = setup().await;
+        let tool = SessionsCreateTool::new(Arc::clone(&metadata));
+
+        tool.execute(json!({"key": "session:dup", "label": "First"}))
+            .await
+            .unwrap();
+        let result = tool
+            .execute(json!({"key": "session:dup", "label": "Second"}))
+            .await
+            .unwrap();
+
+        // The existing entry wins; the second label is not applied.
+        assert_eq!(result["created"], false);
+        assert_eq!(result["label"], "First");
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_or_invalid_key() {
+        let metadata = setup().await;
+        let tool = SessionsCreateTool::new(metadata);
+
+        assert!(tool.execute(json!({})).await.is_err());
+        assert!(tool.execute(json!({"key": "  "})).await.is_err());
+        assert!(tool.execute(json!({"key": "has space"})).await.is_err());
+    }
+}
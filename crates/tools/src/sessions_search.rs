@@ -0,0 +1,152 @@
+//! Agent tool for full-text search across session transcripts.
+
+use std::sync::Arc;
+
+use {
+    anyhow::Result,
+    async_trait::async_trait,
+    moltis_agents::tool_registry::AgentTool,
+    moltis_sessions::store::SessionStore,
+    serde_json::{Value, json},
+};
+
+/// Hard cap on results regardless of the requested limit.
+const MAX_RESULTS: usize = 50;
+
+/// Agent tool that searches all sessions for messages containing a query.
+pub struct SessionsSearchTool {
+    store: Arc<SessionStore>,
+}
+
+impl SessionsSearchTool {
+    pub fn new(store: Arc<SessionStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl AgentTool for SessionsSearchTool {
+    fn name(&self) -> &str {
+        "sessions_search"
+    }
+
+    fn description(&self) -> &str {
+        "Search all session transcripts for messages containing a query string \
+         (case-insensitive). Returns matching sessions with a snippet, role, \
+         and message index — useful for finding which session discussed a topic."
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["query"],
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Text to search for in message content"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of matching sessions (default 10, max 50)"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, params: Value) -> Result<Value> {
+        let query = params
+            .get("query")
+            .and_then(|v| v.as_str())
+            .filter(|q| !q.trim().is_empty())
+            .ok_or_else(|| anyhow::anyhow!("missing 'query'"))?;
+
+        let limit = params
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+            .unwrap_or(10)
+            .min(MAX_RESULTS);
+
+        let results = self.store.search(query, limit).await?;
+
+        Ok(json!({
+            "query": query,
+            "results": results
+                .iter()
+                .map(|r| json!({
+                    "key": r.session_key,
+                    "messageIndex": r.message_index,
+                    "role": r.role,
+                    "snippet": r.snippet,
+                }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup() -> (Arc<SessionStore>, tempfile::TempDir) {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = Arc::new(SessionStore::new(tmp.path().to_path_buf()));
+        store
+            .append(
+                "session:alpha",
+                &json!({"role": "user", "content": "the database timed out"}),
+            )
+            .await
+            .unwrap();
+        store
+            .append(
+                "session:beta",
+                &json!({"role": "assistant", "content": "all systems nominal"}),
+            )
+            .await
+            .unwrap();
+        (store, tmp)
+    }
+
+    #[tokio::test]
+    async fn finds_only_matching_session() {
+        let (store, _tmp) = setup().await;
+        let tool = SessionsSearchTool::new(store);
+
+        let result = tool
+            .execute(json!({ "query": "Database Timed" }))
+            .await
+            .unwrap();
+
+        let results = result["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["key"], "session:alpha");
+        assert_eq!(results[0]["messageIndex"], 0);
+        assert_eq!(results[0]["role"], "user");
+        assert!(
+            results[0]["snippet"]
+                .as_str()
+                .unwrap()
+                .contains("database timed out")
+        );
+    }
+
+    #[tokio::test]
+    async fn no_matches_returns_empty_results() {
+        let (store, _tmp) = setup().await;
+        let tool = SessionsSearchTool::new(store);
+
+        let result = tool.execute(json!({ "query": "nonexistent" })).await.unwrap();
+        assert!(result["results"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn missing_query_errors() {
+        let (store, _tmp) = setup().await;
+        let tool = SessionsSearchTool::new(store);
+
+        assert!(tool.execute(json!({})).await.is_err());
+        assert!(tool.execute(json!({ "query": "  " })).await.is_err());
+    }
+}